@@ -0,0 +1,12 @@
+/// Direction of a line of cells in the grid, always read from its first cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From left to right
+    Horizontal,
+    /// From top to bottom
+    Vertical,
+    /// From top left to bottom right
+    DiagonalLeftToRight,
+    /// From top right to bottom left
+    DiagonalRightToLeft,
+}
@@ -1,9 +1,54 @@
+use std::fmt;
 use std::io;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum AdventOfCodeError {
     IoError(io::Error),
-    RegexError(regex::Error)
+    AutomatonError(aho_corasick::BuildError),
+    ExportError(serde_json::Error),
+    // a flag which has to be followed by a value is the last argument or followed by another flag
+    MissingArgumentError {
+        flag: String,
+    },
+    // a pattern without any grapheme to match, which would match at every cell
+    EmptyPatternError {
+        template: String,
+    },
+    RaggedLineError {
+        line_number: usize,
+        line: String,
+        expected_width: usize,
+        actual_width: usize,
+    },
+}
+
+impl fmt::Display for AdventOfCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdventOfCodeError::IoError(err) => write!(f, "Cannot read the input: {err}"),
+            AdventOfCodeError::AutomatonError(err) => {
+                write!(f, "Cannot build the search automaton: {err}")
+            }
+            AdventOfCodeError::ExportError(err) => write!(f, "Cannot export the matches: {err}"),
+            AdventOfCodeError::MissingArgumentError { flag } => {
+                write!(f, "Flag {flag} has to be followed by a value")
+            }
+            AdventOfCodeError::EmptyPatternError { template } => write!(
+                f,
+                "Pattern {template:?} has no character to match, only wildcards"
+            ),
+            AdventOfCodeError::RaggedLineError {
+                line_number,
+                line,
+                expected_width,
+                actual_width,
+            } => write!(
+                f,
+                "Line {line_number} \"{line}\" has {actual_width} characters, expected {expected_width}"
+            ),
+        }
+    }
 }
 
 impl From<io::Error> for AdventOfCodeError {
@@ -12,8 +57,14 @@ impl From<io::Error> for AdventOfCodeError {
     }
 }
 
-impl From<regex::Error> for AdventOfCodeError {
-    fn from(err: regex::Error) -> Self {
-        AdventOfCodeError::RegexError(err)
+impl From<aho_corasick::BuildError> for AdventOfCodeError {
+    fn from(err: aho_corasick::BuildError) -> Self {
+        AdventOfCodeError::AutomatonError(err)
     }
-}
\ No newline at end of file
+}
+
+impl From<serde_json::Error> for AdventOfCodeError {
+    fn from(err: serde_json::Error) -> Self {
+        AdventOfCodeError::ExportError(err)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::direction::Direction;
+use crate::error::AdventOfCodeError;
+use crate::word_match::WordMatch;
+use serde_json::{json, Value};
+
+fn direction_name(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Horizontal => "horizontal",
+        Direction::Vertical => "vertical",
+        Direction::DiagonalLeftToRight => "diagonal_left_to_right",
+        Direction::DiagonalRightToLeft => "diagonal_right_to_left",
+    }
+}
+
+/// Converts found words into a JSON array. Every match is an object with the word, its direction,
+/// whether it is written backwards and [row, column] positions of its letters.
+///
+/// Returns:
+/// A Result containing a pretty printed JSON string, or an error if serialization fails
+pub fn word_matches_to_json(
+    words: &[&str],
+    matches: &[WordMatch],
+) -> Result<String, AdventOfCodeError> {
+    let matches: Vec<Value> = matches
+        .iter()
+        .map(|word_match| {
+            json!({
+                "word": words[word_match.word_index],
+                "direction": direction_name(&word_match.direction),
+                "reversed": word_match.reversed,
+                "positions": word_match.positions,
+            })
+        })
+        .collect();
+
+    Ok(serde_json::to_string_pretty(&matches)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::word_matches_to_json;
+    use crate::find_words::find_words;
+    use crate::grid::Grid;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn exports_matches_as_json() {
+        let grid = Grid::new("SAMX\nA...").unwrap();
+        let matches = find_words(&grid, &["XMAS", "SA"]).unwrap();
+        let result: Value =
+            serde_json::from_str(&word_matches_to_json(&["XMAS", "SA"], &matches).unwrap())
+                .unwrap();
+
+        assert_eq!(
+            result,
+            json!([
+                {
+                    "word": "SA",
+                    "direction": "horizontal",
+                    "reversed": false,
+                    "positions": [[0, 0], [0, 1]]
+                },
+                {
+                    "word": "XMAS",
+                    "direction": "horizontal",
+                    "reversed": true,
+                    "positions": [[0, 3], [0, 2], [0, 1], [0, 0]]
+                },
+                {
+                    "word": "SA",
+                    "direction": "vertical",
+                    "reversed": false,
+                    "positions": [[0, 0], [1, 0]]
+                }
+            ])
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::grid::Grid;
+use crate::pattern::{Pattern, PatternMatch};
+
+/// Searches for all occurrences of the pattern and all its rotations and reflections in the grid
+///
+/// Returns:
+/// A vector of all matches ordered by the variant of the pattern and position in the grid
+pub fn find_pattern_matches(grid: &Grid, pattern: &Pattern) -> Vec<PatternMatch> {
+    let mut matches = vec![];
+
+    for (variant_index, variant) in pattern.variants().iter().enumerate() {
+        if variant.height() > grid.height() || variant.width() > grid.width() {
+            continue;
+        }
+
+        for row_index in 0..=grid.height() - variant.height() {
+            for column_index in 0..=grid.width() - variant.width() {
+                if variant.matches_at(grid, row_index, column_index) {
+                    matches.push(PatternMatch {
+                        row_index,
+                        column_index,
+                        variant_index,
+                        positions: variant.covered_positions(row_index, column_index),
+                    });
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+/// Searches for all occurrences of the pattern and all its rotations and reflections in the grid
+///
+/// Returns:
+/// A number of all matches
+pub fn count_pattern_matches(grid: &Grid, pattern: &Pattern) -> usize {
+    find_pattern_matches(grid, pattern).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find_patterns::{count_pattern_matches, find_pattern_matches};
+    use crate::grid::Grid;
+    use crate::pattern::{Pattern, PatternMatch};
+
+    const INPUT: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn counts_xmas_shapes() {
+        let pattern = Pattern::new("M.S\n.A.\nM.S").unwrap();
+        let result = count_pattern_matches(&Grid::new(INPUT).unwrap(), &pattern);
+
+        assert_eq!(result, 9);
+    }
+
+    #[test]
+    fn counts_words_in_horizontal_and_vertical_direction() {
+        let pattern = Pattern::new("XMAS").unwrap();
+        let result = count_pattern_matches(&Grid::new(INPUT).unwrap(), &pattern);
+
+        // 3 + 2 horizontal and 1 + 2 vertical occurrences
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn finds_plus_shapes() {
+        let input = "\
+..M..
+.MAS.
+..S..
+.....";
+        let pattern = Pattern::new(".M.\nMAS\n.S.").unwrap();
+        let result = find_pattern_matches(&Grid::new(input).unwrap(), &pattern);
+
+        assert_eq!(
+            result,
+            vec![PatternMatch {
+                row_index: 0,
+                column_index: 1,
+                variant_index: 0,
+                positions: vec![(0, 2), (1, 1), (1, 2), (1, 3), (2, 2)],
+            }]
+        );
+    }
+
+    #[test]
+    fn finds_nothing_when_pattern_is_bigger_than_text() {
+        let pattern = Pattern::new("M.S\n.A.\nM.S").unwrap();
+
+        let small_grid = Grid::new("MAS\nAMS").unwrap();
+        let empty_grid = Grid::new("").unwrap();
+
+        assert_eq!(count_pattern_matches(&small_grid, &pattern), 0);
+        assert_eq!(count_pattern_matches(&empty_grid, &pattern), 0);
+    }
+
+    #[test]
+    fn finds_patterns_of_graphemes() {
+        let input = "\
+ñ.a\u{308}
+.🎄.
+ñ.a\u{308}";
+        let pattern = Pattern::new("ñ.a\u{308}\n.🎄.").unwrap();
+        let result = count_pattern_matches(&Grid::new(input).unwrap(), &pattern);
+
+        assert_eq!(pattern.width(), 3);
+        assert_eq!(result, 2);
+    }
+}
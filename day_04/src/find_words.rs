@@ -0,0 +1,182 @@
+use crate::direction::Direction;
+use crate::error::AdventOfCodeError;
+use crate::grid::Grid;
+use crate::word_match::WordMatch;
+use aho_corasick::AhoCorasick;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Searches for all given words in every row, column and diagonal of the grid, both forwards and
+/// backwards. All words are put into one automaton and every line of the grid is scanned only once,
+/// so the search stays linear in the size of the grid no matter how many words are searched.
+/// Words of a single grapheme are searched in rows only, so every cell matches them once.
+///
+/// Returns:
+/// A Result containing all matches of all words, ordered by the lines of the grid
+pub fn find_words(grid: &Grid, words: &[&str]) -> Result<Vec<WordMatch>, AdventOfCodeError> {
+    let mut patterns: Vec<String> = vec![];
+    // for every pattern of the automaton, the index of its word and whether the word is reversed
+    let mut pattern_words: Vec<(usize, bool)> = vec![];
+
+    for (word_index, word) in words.iter().enumerate() {
+        if word.is_empty() {
+            continue;
+        }
+
+        let reversed_word: String = word.graphemes(true).rev().collect();
+
+        // palindromes read the same in both directions, so they are searched for only once
+        if reversed_word != *word {
+            patterns.push(reversed_word);
+            pattern_words.push((word_index, true));
+        }
+
+        patterns.push(word.to_string());
+        pattern_words.push((word_index, false));
+    }
+
+    let automaton = AhoCorasick::new(&patterns)?;
+    let mut matches = vec![];
+
+    for line in grid.lines() {
+        let mut text = String::new();
+        // byte offsets in the text where each cell of the line starts, plus the end of the text
+        let mut cell_offsets = vec![];
+
+        for (row_index, column_index) in line.positions.iter() {
+            cell_offsets.push(text.len());
+            text.push_str(grid.get(*row_index, *column_index).unwrap_or_default());
+        }
+        cell_offsets.push(text.len());
+
+        for found in automaton.find_overlapping_iter(&text) {
+            // a match has to start and end on cell boundaries, otherwise it only matched a part of
+            // a grapheme, e.g. a letter without its combining mark
+            let (Ok(start_cell), Ok(end_cell)) = (
+                cell_offsets.binary_search(&found.start()),
+                cell_offsets.binary_search(&found.end()),
+            ) else {
+                continue;
+            };
+
+            let (word_index, reversed) = pattern_words[found.pattern().as_usize()];
+
+            // a single cell lies on a line of every direction, its rows are enough
+            if end_cell - start_cell == 1 && line.direction != Direction::Horizontal {
+                continue;
+            }
+
+            let mut positions = line.positions[start_cell..end_cell].to_vec();
+
+            if reversed {
+                positions.reverse();
+            }
+
+            matches.push(WordMatch {
+                word_index,
+                direction: line.direction,
+                reversed,
+                positions,
+            });
+        }
+    }
+
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::Direction;
+    use crate::find_words::find_words;
+    use crate::grid::Grid;
+    use crate::word_match::WordMatch;
+
+    const INPUT: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn finds_all_words_in_one_pass() {
+        let grid = Grid::new(INPUT).unwrap();
+        let result = find_words(&grid, &["XMAS", "MAS", "SAX"]).unwrap();
+        let count = |word_index: usize| {
+            result
+                .iter()
+                .filter(|word_match| word_match.word_index == word_index)
+                .count()
+        };
+
+        assert_eq!(count(0), 18);
+        assert_eq!(count(1), 38);
+        assert_eq!(count(2), 2);
+    }
+
+    #[test]
+    fn finds_reversed_word_positions_in_reading_order() {
+        let grid = Grid::new("SAMX\n....").unwrap();
+        let result = find_words(&grid, &["XMAS"]).unwrap();
+
+        assert_eq!(
+            result,
+            vec![WordMatch {
+                word_index: 0,
+                direction: Direction::Horizontal,
+                reversed: true,
+                positions: vec![(0, 3), (0, 2), (0, 1), (0, 0)],
+            }]
+        );
+    }
+
+    #[test]
+    fn finds_palindromes_only_once() {
+        let grid = Grid::new("ABBA").unwrap();
+        let result = find_words(&grid, &["ABBA"]).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert!(!result[0].reversed);
+    }
+
+    #[test]
+    fn does_not_match_parts_of_graphemes() {
+        let grid = Grid::new("Xe\u{301}.Xe").unwrap();
+        let result = find_words(&grid, &["Xe", "Xe\u{301}"]).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].word_index, 1);
+        assert_eq!(result[0].positions, vec![(0, 0), (0, 1)]);
+        assert_eq!(result[1].word_index, 0);
+        assert_eq!(result[1].positions, vec![(0, 3), (0, 4)]);
+    }
+
+    #[test]
+    fn finds_single_grapheme_words_once_per_cell() {
+        let grid = Grid::new("XMX\nM.X").unwrap();
+        let result = find_words(&grid, &["X"]).unwrap();
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|word_match| word_match.positions.clone())
+                .collect::<Vec<_>>(),
+            vec![vec![(0, 0)], vec![(0, 2)], vec![(1, 2)]]
+        );
+        assert!(result
+            .iter()
+            .all(|word_match| word_match.direction == Direction::Horizontal));
+    }
+
+    #[test]
+    fn finds_nothing_in_empty_grid() {
+        let grid = Grid::new("").unwrap();
+        let result = find_words(&grid, &["XMAS"]).unwrap();
+
+        assert_eq!(result, vec![]);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::direction::Direction;
+use crate::error::AdventOfCodeError;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A straight line of cells going through the whole grid in one direction
+#[derive(Debug, PartialEq)]
+pub struct GridLine {
+    pub direction: Direction,
+    pub positions: Vec<(usize, usize)>,
+}
+
+/// A validated rectangular word search. Every cell holds one grapheme cluster of the input text,
+/// so letters with combining marks or emojis with modifiers take up a single cell.
+#[derive(Debug)]
+pub struct Grid<'a> {
+    cells: Vec<Vec<&'a str>>,
+    width: usize,
+}
+
+impl<'a> Grid<'a> {
+    /// Splits the text into lines of grapheme clusters and checks that all lines are equally long
+    ///
+    /// Returns:
+    /// A Result containing the grid or an error pointing to the first line with a different length
+    pub fn new(text: &'a str) -> Result<Self, AdventOfCodeError> {
+        let cells: Vec<Vec<&str>> = text
+            .lines()
+            .map(|line| line.graphemes(true).collect())
+            .collect();
+        let width = cells.first().map_or(0, |row| row.len());
+
+        for (line_index, row) in cells.iter().enumerate() {
+            if row.len() != width {
+                return Err(AdventOfCodeError::RaggedLineError {
+                    line_number: line_index + 1,
+                    line: row.concat(),
+                    expected_width: width,
+                    actual_width: row.len(),
+                });
+            }
+        }
+
+        Ok(Grid { cells, width })
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the cell on the given position
+    ///
+    /// Returns:
+    /// The grapheme on the position or None if the position is outside the grid
+    pub fn get(&self, row_index: usize, column_index: usize) -> Option<&'a str> {
+        self.cells
+            .get(row_index)
+            .and_then(|row| row.get(column_index))
+            .copied()
+    }
+
+    /// Collects all rows, columns and diagonals of the grid, so every straight word in the grid
+    /// is a substring of exactly one line read forwards or backwards
+    ///
+    /// Returns:
+    /// A vector of all lines of the grid
+    pub fn lines(&self) -> Vec<GridLine> {
+        let height = self.height();
+        let width = self.width;
+        let mut lines = vec![];
+
+        if height == 0 || width == 0 {
+            return lines;
+        }
+
+        for row_index in 0..height {
+            lines.push(GridLine {
+                direction: Direction::Horizontal,
+                positions: (0..width)
+                    .map(|column_index| (row_index, column_index))
+                    .collect(),
+            });
+        }
+
+        for column_index in 0..width {
+            lines.push(GridLine {
+                direction: Direction::Vertical,
+                positions: (0..height)
+                    .map(|row_index| (row_index, column_index))
+                    .collect(),
+            });
+        }
+
+        // diagonals from left to right start in the first column or in the first row
+        let diagonal_starts = (0..height)
+            .rev()
+            .map(|row_index| (row_index, 0))
+            .chain((1..width).map(|column_index| (0, column_index)));
+        for (row_index, column_index) in diagonal_starts {
+            let length = (height - row_index).min(width - column_index);
+            lines.push(GridLine {
+                direction: Direction::DiagonalLeftToRight,
+                positions: (0..length)
+                    .map(|step| (row_index + step, column_index + step))
+                    .collect(),
+            });
+        }
+
+        // diagonals from right to left start in the first row or in the last column
+        let diagonal_starts = (0..width)
+            .map(|column_index| (0, column_index))
+            .chain((1..height).map(|row_index| (row_index, width - 1)));
+        for (row_index, column_index) in diagonal_starts {
+            let length = (height - row_index).min(column_index + 1);
+            lines.push(GridLine {
+                direction: Direction::DiagonalRightToLeft,
+                positions: (0..length)
+                    .map(|step| (row_index + step, column_index - step))
+                    .collect(),
+            });
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::Direction;
+    use crate::error::AdventOfCodeError;
+    use crate::grid::{Grid, GridLine};
+
+    #[test]
+    fn creates_grid_of_graphemes() {
+        let grid = Grid::new("e\u{301}X👍🏽\nMAS").unwrap();
+
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.get(0, 0), Some("e\u{301}"));
+        assert_eq!(grid.get(0, 2), Some("👍🏽"));
+        assert_eq!(grid.get(1, 2), Some("S"));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+    }
+
+    #[test]
+    fn creates_empty_grid() {
+        let grid = Grid::new("").unwrap();
+
+        assert_eq!(grid.height(), 0);
+        assert_eq!(grid.width(), 0);
+    }
+
+    #[test]
+    fn rejects_ragged_lines() {
+        let result = Grid::new("XMAS\nXMA\nXMAS");
+
+        match result {
+            Err(AdventOfCodeError::RaggedLineError {
+                line_number,
+                line,
+                expected_width,
+                actual_width,
+            }) => {
+                assert_eq!(line_number, 2);
+                assert_eq!(line, "XMA");
+                assert_eq!(expected_width, 4);
+                assert_eq!(actual_width, 3);
+            }
+            _ => panic!("Expected a ragged line error"),
+        }
+    }
+
+    #[test]
+    fn collects_all_lines() {
+        let grid = Grid::new("ABC\nDEF").unwrap();
+        let result = grid.lines();
+
+        assert_eq!(result.len(), 13);
+        assert_eq!(
+            result
+                .iter()
+                .filter(|line| line.direction == Direction::Horizontal)
+                .count(),
+            2
+        );
+        assert_eq!(
+            result
+                .iter()
+                .filter(|line| line.direction == Direction::Vertical)
+                .count(),
+            3
+        );
+        assert_eq!(
+            result
+                .iter()
+                .filter(|line| line.direction == Direction::DiagonalLeftToRight)
+                .map(|line| line.positions.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![(1, 0)],
+                vec![(0, 0), (1, 1)],
+                vec![(0, 1), (1, 2)],
+                vec![(0, 2)]
+            ]
+        );
+        assert_eq!(
+            result.last().unwrap(),
+            &GridLine {
+                direction: Direction::DiagonalRightToLeft,
+                positions: vec![(1, 2)],
+            }
+        );
+    }
+
+    #[test]
+    fn collects_no_lines_of_empty_grid() {
+        let grid = Grid::new("").unwrap();
+
+        assert_eq!(grid.lines(), vec![]);
+    }
+}
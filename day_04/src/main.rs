@@ -1,16 +1,60 @@
+mod direction;
 mod error;
+mod export;
+mod find_patterns;
+mod find_words;
 mod find_xmas_shapes;
 mod find_xmas_substrings;
+mod grid;
+mod pattern;
+mod render;
+mod word_match;
 mod xmas;
 
 use crate::error::AdventOfCodeError;
-use crate::find_xmas_shapes::find_all_xmas_shapes;
-use crate::find_xmas_substrings::find_all_xmas_substrings;
-use std::fs;
+use crate::export::word_matches_to_json;
+use crate::find_patterns::find_pattern_matches;
+use crate::find_words::find_words;
+use crate::find_xmas_shapes::{find_all_xmas_shapes, XMAS_SHAPE_TEMPLATE};
+use crate::find_xmas_substrings::{find_all_xmas_substrings, XMAS_WORD};
+use crate::grid::Grid;
+use crate::pattern::Pattern;
+use crate::render::{render_pattern_matches, render_word_matches};
+use std::{env, fs};
+
+// prints the grid with only letters of found words and shapes visible
+const RENDER_FLAG: &str = "--render";
+// same as --render, but letters of found words are colored by their direction
+const COLOR_FLAG: &str = "--color";
+// followed by a file path, exports positions of found words as JSON
+const JSON_FLAG: &str = "--json";
+
+/// Gets the file path following the JSON flag, another flag is not taken as the path
+///
+/// Returns:
+/// A Result containing the path or None if the flag is not used, or an error if the path is missing
+fn parse_json_path(args: &[String]) -> Result<Option<&String>, AdventOfCodeError> {
+    let Some(flag_index) = args.iter().position(|arg| arg == JSON_FLAG) else {
+        return Ok(None);
+    };
+
+    args.get(flag_index + 1)
+        .filter(|path| !path.starts_with("--"))
+        .map(Some)
+        .ok_or_else(|| AdventOfCodeError::MissingArgumentError {
+            flag: JSON_FLAG.to_string(),
+        })
+}
 
 fn main() -> Result<(), AdventOfCodeError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let colored = args.iter().any(|arg| arg == COLOR_FLAG);
+    let render = colored || args.iter().any(|arg| arg == RENDER_FLAG);
+    let json_path = parse_json_path(&args)?;
+
     let text = fs::read_to_string("./data/input.txt")?;
-    let xmas = find_all_xmas_substrings(&text).unwrap();
+    let grid = Grid::new(&text)?;
+    let xmas = find_all_xmas_substrings(&grid)?;
 
     println!(
         "Horizontal left to right search count: {}",
@@ -47,8 +91,26 @@ fn main() -> Result<(), AdventOfCodeError> {
     println!("Total count of xmas substring: {}", xmas.total_count);
 
     println!("---------------------------------------");
-    let xmas_shapes_count = find_all_xmas_shapes(&text);
+    let xmas_shapes_count = find_all_xmas_shapes(&grid)?;
     println!("Total count of x-mas shapes: {}", xmas_shapes_count);
 
+    if render || json_path.is_some() {
+        let word_matches = find_words(&grid, &[XMAS_WORD])?;
+
+        if render {
+            let shape_matches = find_pattern_matches(&grid, &Pattern::new(XMAS_SHAPE_TEMPLATE)?);
+
+            println!("---------------------------------------");
+            print!("{}", render_word_matches(&grid, &word_matches, colored));
+            println!("---------------------------------------");
+            print!("{}", render_pattern_matches(&grid, &shape_matches));
+        }
+
+        if let Some(path) = json_path {
+            fs::write(path, word_matches_to_json(&[XMAS_WORD], &word_matches)?)?;
+            println!("Positions of xmas substrings exported to {path}");
+        }
+    }
+
     Ok(())
 }
@@ -0,0 +1,202 @@
+use crate::error::AdventOfCodeError;
+use crate::grid::Grid;
+use unicode_segmentation::UnicodeSegmentation;
+
+const WILDCARD_SYMBOL: &str = ".";
+
+/// A small rectangular template which can be searched for in the word search. Every cell either
+/// holds a grapheme which has to be matched exactly or a wildcard (written as '.') which matches
+/// any character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    cells: Vec<Vec<Option<String>>>,
+}
+
+impl Pattern {
+    /// Creates a pattern from a template text, e.g. "M.S\n.A.\nM.S" for the x-mas shape.
+    /// Shorter lines are filled up with wildcards so the pattern is always rectangular.
+    ///
+    /// Returns:
+    /// A Result containing the pattern, or an error if the template has no grapheme to match,
+    /// because such a pattern would match at every cell
+    pub fn new(template: &str) -> Result<Self, AdventOfCodeError> {
+        let mut cells: Vec<Vec<Option<String>>> = template
+            .lines()
+            .map(|line| {
+                line.graphemes(true)
+                    .map(|grapheme| match grapheme {
+                        WILDCARD_SYMBOL => None,
+                        _ => Some(grapheme.to_string()),
+                    })
+                    .collect()
+            })
+            .collect();
+        let width = cells.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        if cells.iter().flatten().all(|cell| cell.is_none()) {
+            return Err(AdventOfCodeError::EmptyPatternError {
+                template: template.to_string(),
+            });
+        }
+
+        for row in cells.iter_mut() {
+            row.resize(width, None);
+        }
+
+        Ok(Pattern { cells })
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.first().map_or(0, |row| row.len())
+    }
+
+    /// Rotates the pattern by 90 degrees clockwise
+    fn rotate(&self) -> Self {
+        let cells = (0..self.width())
+            .map(|column_index| {
+                (0..self.height())
+                    .rev()
+                    .map(|row_index| self.cells[row_index][column_index].clone())
+                    .collect()
+            })
+            .collect();
+
+        Pattern { cells }
+    }
+
+    /// Mirrors the pattern along its vertical axis
+    fn reflect(&self) -> Self {
+        let cells = self
+            .cells
+            .iter()
+            .map(|row| row.iter().rev().cloned().collect())
+            .collect();
+
+        Pattern { cells }
+    }
+
+    /// Generates all rotations and reflections of the pattern
+    ///
+    /// Returns:
+    /// A vector of unique variants of the pattern, the pattern itself being always the first one
+    pub fn variants(&self) -> Vec<Pattern> {
+        let mut variants: Vec<Pattern> = vec![];
+        let mut rotation = self.clone();
+
+        for _ in 0..4 {
+            for variant in [rotation.clone(), rotation.reflect()] {
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+            }
+
+            rotation = rotation.rotate();
+        }
+
+        variants
+    }
+
+    /// Checks if the pattern placed with its top left corner on the given position matches the
+    /// cells of the grid
+    ///
+    /// Returns:
+    /// True if every non-wildcard cell of the pattern matches the grid cell under it
+    pub fn matches_at(&self, grid: &Grid, row_index: usize, column_index: usize) -> bool {
+        self.cells
+            .iter()
+            .enumerate()
+            .all(|(pattern_row_index, row)| {
+                row.iter()
+                    .enumerate()
+                    .all(|(pattern_column_index, cell)| match cell {
+                        None => true,
+                        Some(grapheme) => grid
+                            .get(
+                                row_index + pattern_row_index,
+                                column_index + pattern_column_index,
+                            )
+                            .is_some_and(|grid_grapheme| grid_grapheme == grapheme),
+                    })
+            })
+    }
+
+    /// Gets positions of all non-wildcard cells when the pattern is placed on the given position
+    ///
+    /// Returns:
+    /// A vector of (row, column) positions covered by the pattern
+    pub fn covered_positions(&self, row_index: usize, column_index: usize) -> Vec<(usize, usize)> {
+        let mut positions = vec![];
+
+        for (pattern_row_index, row) in self.cells.iter().enumerate() {
+            for (pattern_column_index, cell) in row.iter().enumerate() {
+                if cell.is_some() {
+                    positions.push((
+                        row_index + pattern_row_index,
+                        column_index + pattern_column_index,
+                    ));
+                }
+            }
+        }
+
+        positions
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PatternMatch {
+    pub row_index: usize,
+    pub column_index: usize,
+    pub variant_index: usize,
+    pub positions: Vec<(usize, usize)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AdventOfCodeError;
+    use crate::pattern::Pattern;
+
+    #[test]
+    fn creates_rectangular_pattern() {
+        let pattern = Pattern::new("M.S\n.A\nM.S").unwrap();
+
+        assert_eq!(pattern.height(), 3);
+        assert_eq!(pattern.width(), 3);
+        assert_eq!(pattern, Pattern::new("M.S\n.A.\nM.S").unwrap());
+    }
+
+    #[test]
+    fn generates_unique_variants() {
+        let xmas_shape = Pattern::new("M.S\n.A.\nM.S").unwrap();
+        let plus_shape = Pattern::new(".M.\nMAS\n.S.").unwrap();
+        let word = Pattern::new("XMAS").unwrap();
+        let corner = Pattern::new("XM\n.A").unwrap();
+
+        assert_eq!(xmas_shape.variants().len(), 4);
+        assert_eq!(xmas_shape.variants()[0], xmas_shape);
+        assert_eq!(plus_shape.variants().len(), 4);
+        assert_eq!(word.variants().len(), 4);
+        assert_eq!(corner.variants().len(), 8);
+    }
+
+    #[test]
+    fn rotates_and_reflects_pattern() {
+        let pattern = Pattern::new("XM\n.A").unwrap();
+
+        assert_eq!(pattern.rotate(), Pattern::new(".X\nAM").unwrap());
+        assert_eq!(pattern.reflect(), Pattern::new("MX\nA.").unwrap());
+    }
+
+    #[test]
+    fn rejects_pattern_without_graphemes() {
+        for template in ["", "\n", "...", ".\n.."] {
+            assert!(matches!(
+                Pattern::new(template),
+                Err(AdventOfCodeError::EmptyPatternError { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::direction::Direction;
+use crate::grid::Grid;
+use crate::pattern::PatternMatch;
+use crate::word_match::WordMatch;
+use std::collections::HashMap;
+
+const HIDDEN_CELL_SYMBOL: &str = ".";
+const ANSI_RESET: &str = "\x1b[0m";
+
+fn direction_color(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Horizontal => "\x1b[31m",
+        Direction::Vertical => "\x1b[32m",
+        Direction::DiagonalLeftToRight => "\x1b[33m",
+        Direction::DiagonalRightToLeft => "\x1b[36m",
+    }
+}
+
+/// Renders the grid where only highlighted cells are kept and all other cells are replaced by '.'
+///
+/// Returns:
+/// A multiline string with the rendered grid
+fn render_cells(grid: &Grid, highlighted: &HashMap<(usize, usize), Option<&str>>) -> String {
+    let mut rendered = String::new();
+
+    for row_index in 0..grid.height() {
+        for column_index in 0..grid.width() {
+            let cell = grid.get(row_index, column_index).unwrap_or_default();
+
+            match highlighted.get(&(row_index, column_index)) {
+                Some(Some(color)) => rendered.push_str(&format!("{color}{cell}{ANSI_RESET}")),
+                Some(None) => rendered.push_str(cell),
+                None => rendered.push_str(HIDDEN_CELL_SYMBOL),
+            }
+        }
+
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+/// Renders the grid with only letters of found words visible, as in the puzzle description.
+/// If colored, every letter gets an ANSI color of the direction of the first word it belongs to.
+///
+/// Returns:
+/// A multiline string with the rendered grid
+pub fn render_word_matches(grid: &Grid, matches: &[WordMatch], colored: bool) -> String {
+    let mut highlighted = HashMap::new();
+
+    for word_match in matches {
+        let color = colored.then(|| direction_color(&word_match.direction));
+
+        for position in word_match.positions.iter() {
+            highlighted.entry(*position).or_insert(color);
+        }
+    }
+
+    render_cells(grid, &highlighted)
+}
+
+/// Renders the grid with only cells covered by found patterns visible
+///
+/// Returns:
+/// A multiline string with the rendered grid
+pub fn render_pattern_matches(grid: &Grid, matches: &[PatternMatch]) -> String {
+    let highlighted = matches
+        .iter()
+        .flat_map(|pattern_match| pattern_match.positions.iter())
+        .map(|position| (*position, None))
+        .collect();
+
+    render_cells(grid, &highlighted)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find_patterns::find_pattern_matches;
+    use crate::find_words::find_words;
+    use crate::grid::Grid;
+    use crate::pattern::Pattern;
+    use crate::render::{render_pattern_matches, render_word_matches};
+
+    const INPUT: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn renders_found_words() {
+        let grid = Grid::new(INPUT).unwrap();
+        let matches = find_words(&grid, &["XMAS"]).unwrap();
+        let result = render_word_matches(&grid, &matches, false);
+
+        assert_eq!(
+            result,
+            "\
+....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX
+"
+        );
+    }
+
+    #[test]
+    fn renders_found_words_in_color() {
+        let grid = Grid::new("XMAS\nM...\nA...\nS...").unwrap();
+        let matches = find_words(&grid, &["XMAS"]).unwrap();
+        let result = render_word_matches(&grid, &matches, true);
+
+        assert_eq!(
+            result.lines().next().unwrap(),
+            "\x1b[31mX\x1b[0m\x1b[31mM\x1b[0m\x1b[31mA\x1b[0m\x1b[31mS\x1b[0m"
+        );
+        assert_eq!(result.lines().nth(1).unwrap(), "\x1b[32mM\x1b[0m...");
+    }
+
+    #[test]
+    fn renders_found_patterns() {
+        let grid = Grid::new(INPUT).unwrap();
+        let matches = find_pattern_matches(&grid, &Pattern::new("M.S\n.A.\nM.S").unwrap());
+        let result = render_pattern_matches(&grid, &matches);
+
+        assert_eq!(
+            result,
+            "\
+.M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........
+"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use crate::direction::Direction;
+
+#[derive(Debug, PartialEq)]
+pub struct WordMatch {
+    /// Index of the found word in the list of searched words
+    pub word_index: usize,
+    pub direction: Direction,
+    /// True if the word is written backwards, e.g. from right to left for a horizontal line
+    pub reversed: bool,
+    /// (row, column) positions of the word's letters in the order they are read in the word
+    pub positions: Vec<(usize, usize)>,
+}
@@ -11,6 +11,7 @@ pub struct Xmas {
 }
 
 impl Xmas {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         horizontal_left_to_right_search_count: usize,
         horizontal_right_to_left_search_count: usize,
@@ -1,25 +1,50 @@
-pub fn is_update_correctly_ordered(rules: &Vec<Vec<u8>>, update: &Vec<u8>) -> bool {
-    let mut rule: Option<&Vec<u8>>;
+use crate::order_violation::{OrderViolation, ViolationKind};
+use crate::rule_graph::RuleGraph;
 
+pub type Page = u32;
+
+/// A rule (page, follower) saying that the page has to be printed before the follower
+pub type Rule = (Page, Page);
+
+/// Finds the first pair of pages in the update for which the rule "page|following page" is missing
+///
+/// Returns:
+/// The first violated pair ordered by positions in the update, or None if the update is correct
+pub fn find_first_violation(graph: &RuleGraph, update: &[Page]) -> Option<OrderViolation> {
     for (page_index, page_number) in update.iter().enumerate() {
-        for following_number in update.iter().skip(page_index + 1) {
-            rule = rules
-                .iter()
-                .find(|rule| rule[0] == *page_number && rule[1] == *following_number);
+        for (following_index, following_number) in update.iter().enumerate().skip(page_index + 1) {
+            if !graph.before(*page_number, *following_number) {
+                let kind = if graph.before(*following_number, *page_number) {
+                    ViolationKind::ContradictedRule
+                } else {
+                    ViolationKind::MissingRule
+                };
 
-            if rule.is_none() {
-                return false;
+                return Some(OrderViolation {
+                    first_position: page_index,
+                    first_page: *page_number,
+                    second_position: following_index,
+                    second_page: *following_number,
+                    kind,
+                });
             }
         }
     }
 
-    true
+    None
+}
+
+pub fn is_update_correctly_ordered(graph: &RuleGraph, update: &[Page]) -> bool {
+    find_first_violation(graph, update).is_none()
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
-    use crate::common::is_update_correctly_ordered;
+    use crate::common::{find_first_violation, is_update_correctly_ordered};
+    use crate::order_violation::{OrderViolation, ViolationKind};
     use crate::parse::parse_input;
+    use crate::rule_graph::RuleGraph;
 
     const INPUT: &str = "\
 47|53
@@ -54,7 +79,8 @@ mod tests {
     #[test]
     fn update_is_correct() {
         let (rules, updates) = parse_input(INPUT).unwrap();
-        let result = is_update_correctly_ordered(&rules, updates.get(0).unwrap());
+        let graph = RuleGraph::new(&rules);
+        let result = is_update_correctly_ordered(&graph, &updates[0]);
 
         assert_eq!(result, true);
     }
@@ -62,12 +88,41 @@ mod tests {
     #[test]
     fn updates_are_incorrect() {
         let (rules, updates) = parse_input(INPUT).unwrap();
-        let result1 = is_update_correctly_ordered(&rules, updates.get(3).unwrap());
-        let result2 = is_update_correctly_ordered(&rules, updates.get(4).unwrap());
-        let result3 = is_update_correctly_ordered(&rules, updates.get(5).unwrap());
+        let graph = RuleGraph::new(&rules);
+        let result1 = is_update_correctly_ordered(&graph, &updates[3]);
+        let result2 = is_update_correctly_ordered(&graph, &updates[4]);
+        let result3 = is_update_correctly_ordered(&graph, &updates[5]);
 
         assert_eq!(result1, false);
         assert_eq!(result2, false);
         assert_eq!(result3, false);
     }
+
+    #[test]
+    fn finds_first_violation() {
+        let (rules, updates) = parse_input(INPUT).unwrap();
+        let graph = RuleGraph::new(&rules);
+
+        assert_eq!(find_first_violation(&graph, &updates[0]), None);
+        assert_eq!(
+            find_first_violation(&graph, &updates[3]),
+            Some(OrderViolation {
+                first_position: 0,
+                first_page: 75,
+                second_position: 1,
+                second_page: 97,
+                kind: ViolationKind::ContradictedRule,
+            })
+        );
+        assert_eq!(
+            find_first_violation(&graph, &[47, 99, 53]),
+            Some(OrderViolation {
+                first_position: 0,
+                first_page: 47,
+                second_position: 1,
+                second_page: 99,
+                kind: ViolationKind::MissingRule,
+            })
+        );
+    }
 }
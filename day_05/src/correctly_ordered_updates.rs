@@ -1,20 +1,21 @@
-use crate::common::is_update_correctly_ordered;
+use crate::common::{is_update_correctly_ordered, Page};
+use crate::rule_graph::RuleGraph;
 
 fn find_correctly_ordered_updates<'a>(
-    rules: &Vec<Vec<u8>>,
-    updates: &'a Vec<Vec<u8>>,
-) -> Vec<&'a Vec<u8>> {
+    graph: &RuleGraph,
+    updates: &'a [Vec<Page>],
+) -> Vec<&'a Vec<Page>> {
     updates
         .iter()
-        .filter(|update| is_update_correctly_ordered(rules, update))
+        .filter(|update| is_update_correctly_ordered(graph, update))
         .collect()
 }
 
 pub fn get_middle_page_sum_of_correctly_ordered_updates(
-    rules: &Vec<Vec<u8>>,
-    updates: &Vec<Vec<u8>>,
+    graph: &RuleGraph,
+    updates: &[Vec<Page>],
 ) -> usize {
-    let correct_updates = find_correctly_ordered_updates(rules, updates);
+    let correct_updates = find_correctly_ordered_updates(graph, updates);
 
     correct_updates
         .iter()
@@ -32,6 +33,7 @@ mod tests {
         find_correctly_ordered_updates, get_middle_page_sum_of_correctly_ordered_updates,
     };
     use crate::parse::parse_input;
+    use crate::rule_graph::RuleGraph;
 
     const INPUT: &str = "\
 47|53
@@ -66,7 +68,8 @@ mod tests {
     #[test]
     fn finds_all_correctly_ordered_updates() {
         let (rules, updates) = parse_input(INPUT).unwrap();
-        let result = find_correctly_ordered_updates(&rules, &updates);
+        let graph = RuleGraph::new(&rules);
+        let result = find_correctly_ordered_updates(&graph, &updates);
 
         assert_eq!(result.len(), 3);
         assert_eq!(result[0], &vec![75, 47, 61, 53, 29]);
@@ -77,7 +80,8 @@ mod tests {
     #[test]
     fn gets_correct_sum() {
         let (rules, updates) = parse_input(INPUT).unwrap();
-        let result = get_middle_page_sum_of_correctly_ordered_updates(&rules, &updates);
+        let graph = RuleGraph::new(&rules);
+        let result = get_middle_page_sum_of_correctly_ordered_updates(&graph, &updates);
 
         assert_eq!(result, 143);
     }
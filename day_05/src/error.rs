@@ -1,20 +1,84 @@
+use crate::common::Page;
+use std::fmt;
 use std::io;
 use std::num;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum AdventOfCodeError {
     IoError(io::Error),
-    ParseError(num::ParseIntError),
+    ParseError {
+        line_number: usize,
+        line: String,
+        err: num::ParseIntError,
+    },
+    InvalidRuleError {
+        line_number: usize,
+        line: String,
+    },
+    // a page printed more than once in an update, the position is the one of its repetition
+    DuplicatePageError {
+        page: Page,
+        position: usize,
+    },
+    // pages of an update which depend on each other in a cycle, sorted by page number
+    CycleError(Vec<Page>),
+    // pages which could all be printed at the position of an update, so the rules allow more
+    // than one order
+    AmbiguousOrderingError {
+        position: usize,
+        pages: Vec<Page>,
+    },
+    // updates which should be turned into each other by moves, but do not hold the same unique
+    // pages
+    MismatchedUpdatesError {
+        original_update: Vec<Page>,
+        fixed_update: Vec<Page>,
+    },
 }
 
-impl From<io::Error> for AdventOfCodeError {
-    fn from(err: io::Error) -> Self {
-        AdventOfCodeError::IoError(err)
+impl fmt::Display for AdventOfCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdventOfCodeError::IoError(err) => write!(f, "Cannot read the input: {err}"),
+            AdventOfCodeError::ParseError {
+                line_number,
+                line,
+                err,
+            } => write!(
+                f,
+                "Cannot parse a page number on line {line_number} \"{line}\": {err}"
+            ),
+            AdventOfCodeError::InvalidRuleError { line_number, line } => {
+                write!(
+                    f,
+                    "Line {line_number} \"{line}\" is not a rule in the format a|b"
+                )
+            }
+            AdventOfCodeError::DuplicatePageError { page, position } => write!(
+                f,
+                "Page {page} is printed again at position {position} of the update"
+            ),
+            AdventOfCodeError::CycleError(pages) => {
+                write!(f, "Rules between pages {pages:?} form a cycle")
+            }
+            AdventOfCodeError::AmbiguousOrderingError { position, pages } => write!(
+                f,
+                "Rules allow any of pages {pages:?} at position {position}, so the order is not unique"
+            ),
+            AdventOfCodeError::MismatchedUpdatesError {
+                original_update,
+                fixed_update,
+            } => write!(
+                f,
+                "Updates {original_update:?} and {fixed_update:?} do not hold the same unique pages"
+            ),
+        }
     }
 }
 
-impl From<num::ParseIntError> for AdventOfCodeError {
-    fn from(err: num::ParseIntError) -> Self {
-        AdventOfCodeError::ParseError(err)
+impl From<io::Error> for AdventOfCodeError {
+    fn from(err: io::Error) -> Self {
+        AdventOfCodeError::IoError(err)
     }
 }
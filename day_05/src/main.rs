@@ -2,20 +2,103 @@ mod common;
 mod correctly_ordered_updates;
 mod error;
 mod incorrectly_ordered_updates;
+mod order_violation;
 mod parse;
+mod print_queue;
+mod repair_moves;
+mod rule_graph;
+mod topological_sort;
+mod validation;
 
+use crate::common::find_first_violation;
 use crate::correctly_ordered_updates::get_middle_page_sum_of_correctly_ordered_updates;
 use crate::error::AdventOfCodeError;
 use crate::incorrectly_ordered_updates::get_middle_page_sum_of_fixed_incorrectly_ordered_updates;
 use crate::parse::parse_input;
-use std::fs;
+use crate::print_queue::{stream_updates, PrintQueue};
+use crate::repair_moves::find_repair_moves;
+use crate::rule_graph::RuleGraph;
+use crate::topological_sort::{all_orderings, sort_update};
+use crate::validation::validate_input;
+use std::io::BufReader;
+use std::{env, fs};
+
+// checks the input for problems instead of computing the sums
+const VALIDATE_COMMAND: &str = "validate";
+// explains why each incorrectly ordered update is wrong and how it is fixed
+const EXPLAIN_COMMAND: &str = "explain";
+// checks updates while the input is read instead of parsing the whole file first
+const STREAM_COMMAND: &str = "stream";
+// the number of valid orders listed when the rules allow more than one order of an update
+const LISTED_ORDERINGS_LIMIT: usize = 10;
 
 fn main() -> Result<(), AdventOfCodeError> {
+    let command = env::args().nth(1);
+
+    if command.as_deref() == Some(STREAM_COMMAND) {
+        let reader = BufReader::new(fs::File::open("./data/input.txt")?);
+        let mut queue = PrintQueue::default();
+        let mut middle_pages_sum = 0;
+
+        stream_updates(reader, &mut queue, |update| {
+            if update.is_correctly_ordered() {
+                middle_pages_sum += update.pages[update.pages.len() / 2] as usize;
+            }
+        })?;
+        println!("The sum of all correctly ordered updates: {middle_pages_sum}");
+
+        return Ok(());
+    }
+
     let input = fs::read_to_string("./data/input.txt")?;
     let (rules, updates) = parse_input(&input)?;
-    let middle_pages_sum = get_middle_page_sum_of_correctly_ordered_updates(&rules, &updates);
+
+    if command.as_deref() == Some(VALIDATE_COMMAND) {
+        print!("{}", validate_input(&rules, &updates));
+
+        return Ok(());
+    }
+
+    let graph = RuleGraph::new(&rules);
+
+    if command.as_deref() == Some(EXPLAIN_COMMAND) {
+        for update in updates.iter() {
+            if let Some(violation) = find_first_violation(&graph, update) {
+                println!("Update {update:?}");
+                println!("  {violation}");
+
+                // an update which cannot be sorted is explained without moves, so the other
+                // updates are still explained
+                match sort_update(&graph, update) {
+                    Ok(fixed_update) => {
+                        for page_move in find_repair_moves(update, &fixed_update)? {
+                            println!("  {page_move}");
+                        }
+                        println!("  Fixed update {fixed_update:?}");
+                    }
+                    Err(err @ AdventOfCodeError::AmbiguousOrderingError { .. }) => {
+                        println!("  Cannot fix the update: {err}");
+                        // pages after the ambiguous position can still form a cycle
+                        match all_orderings(&graph, update, LISTED_ORDERINGS_LIMIT) {
+                            Ok(orderings) => {
+                                for ordering in orderings {
+                                    println!("  Valid order {ordering:?}");
+                                }
+                            }
+                            Err(err) => println!("  Cannot list the valid orders: {err}"),
+                        }
+                    }
+                    Err(err) => println!("  Cannot fix the update: {err}"),
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    let middle_pages_sum = get_middle_page_sum_of_correctly_ordered_updates(&graph, &updates);
     let middle_pages_sum_of_fixed_updates =
-        get_middle_page_sum_of_fixed_incorrectly_ordered_updates(rules, updates);
+        get_middle_page_sum_of_fixed_incorrectly_ordered_updates(&graph, &updates)?;
 
     println!("The sum of all correctly ordered updates: {middle_pages_sum}");
     println!(
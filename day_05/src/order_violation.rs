@@ -0,0 +1,47 @@
+use crate::common::Page;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ViolationKind {
+    /// There is no rule between the two pages, so their order is not allowed
+    MissingRule,
+    /// The rule "second|first" requires the opposite order of the two pages
+    ContradictedRule,
+}
+
+/// The first pair of pages in an update which is not in the order required by the rules
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderViolation {
+    pub first_position: usize,
+    pub first_page: Page,
+    pub second_position: usize,
+    pub second_page: Page,
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for OrderViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let OrderViolation {
+            first_position,
+            first_page,
+            second_position,
+            second_page,
+            kind,
+        } = self;
+
+        match kind {
+            ViolationKind::MissingRule => write!(
+                f,
+                "Page {first_page} at position {first_position} is printed before page \
+                 {second_page} at position {second_position}, but rule \
+                 {first_page}|{second_page} is missing"
+            ),
+            ViolationKind::ContradictedRule => write!(
+                f,
+                "Page {first_page} at position {first_position} is printed before page \
+                 {second_page} at position {second_position}, which contradicts rule \
+                 {second_page}|{first_page}"
+            ),
+        }
+    }
+}
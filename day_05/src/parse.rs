@@ -1,35 +1,166 @@
+use crate::common::{Page, Rule};
 use crate::error::AdventOfCodeError;
 
 const RULES_SEPARATOR: &str = "|";
 const UPDATES_SEPARATOR: &str = ",";
 
-pub fn parse_input(text: &str) -> Result<(Vec<Vec<u8>>, Vec<Vec<u8>>), AdventOfCodeError> {
-    let mut rules: Vec<Vec<u8>> = vec![];
-    let mut updates: Vec<Vec<u8>> = vec![];
-
-    for line in text.lines() {
-        if line.contains(RULES_SEPARATOR) {
-            let parts = line
-                .split(RULES_SEPARATOR)
-                .map(|item| {
-                    item.trim()
-                        .parse()
-                        .map_err(|err| AdventOfCodeError::ParseError(err))
-                })
-                .collect::<Result<Vec<u8>, AdventOfCodeError>>()?;
-            rules.push(parts);
-        } else if line.contains(UPDATES_SEPARATOR) {
-            let parts = line
-                .split(UPDATES_SEPARATOR)
-                .map(|item| {
-                    item.trim()
-                        .parse()
-                        .map_err(|err| AdventOfCodeError::ParseError(err))
-                })
-                .collect::<Result<Vec<u8>, AdventOfCodeError>>()?;
-            updates.push(parts);
+type RulesAndUpdates = (Vec<Rule>, Vec<Vec<Page>>);
+
+fn parse_page(line_number: usize, line: &str, item: &str) -> Result<Page, AdventOfCodeError> {
+    item.trim()
+        .parse()
+        .map_err(|err| AdventOfCodeError::ParseError {
+            line_number,
+            line: line.to_string(),
+            err,
+        })
+}
+
+/// Parses a rule line in the format "page|follower"
+///
+/// Returns:
+/// A Result containing the rule or an error with the line number if the line is not a valid rule
+fn parse_rule(line_number: usize, line: &str) -> Result<Rule, AdventOfCodeError> {
+    match line.split_once(RULES_SEPARATOR) {
+        Some((page, follower)) if !follower.contains(RULES_SEPARATOR) => Ok((
+            parse_page(line_number, line, page)?,
+            parse_page(line_number, line, follower)?,
+        )),
+        _ => Err(AdventOfCodeError::InvalidRuleError {
+            line_number,
+            line: line.to_string(),
+        }),
+    }
+}
+
+/// Parses an update line in the format "page,page,...", a single page is a valid update too
+///
+/// Returns:
+/// A Result containing pages of the update or an error with the line number of an invalid page
+fn parse_update(line_number: usize, line: &str) -> Result<Vec<Page>, AdventOfCodeError> {
+    line.split(UPDATES_SEPARATOR)
+        .map(|item| parse_page(line_number, line, item))
+        .collect()
+}
+
+/// A non-blank line of the input, parsed according to the section it belongs to
+pub enum InputLine {
+    Rule(Rule),
+    Update(Vec<Page>),
+}
+
+/// Remembers which section of the input is being read, so the input can be parsed line by line.
+/// The rules section and the updates section are separated by the first blank line, blank lines
+/// inside the updates section are skipped.
+#[derive(Debug, Default)]
+pub struct SectionParser {
+    is_updates_section: bool,
+}
+
+impl SectionParser {
+    /// Parses the line as a rule or an update depending on the current section
+    ///
+    /// Returns:
+    /// A Result containing the parsed line or None for a blank line, or an error with the line
+    /// number if the line is invalid
+    pub fn parse_line(
+        &mut self,
+        line_number: usize,
+        line: &str,
+    ) -> Result<Option<InputLine>, AdventOfCodeError> {
+        let line = line.trim();
+
+        if line.is_empty() {
+            self.is_updates_section = true;
+
+            return Ok(None);
+        }
+
+        if self.is_updates_section {
+            Ok(Some(InputLine::Update(parse_update(line_number, line)?)))
+        } else {
+            Ok(Some(InputLine::Rule(parse_rule(line_number, line)?)))
+        }
+    }
+}
+
+/// Parses the input consisting of the rules section and the updates section separated by the first
+/// blank line. Blank lines inside the updates section are skipped.
+///
+/// Returns:
+/// A Result containing rules and updates, or an error with the number of the first invalid line
+pub fn parse_input(text: &str) -> Result<RulesAndUpdates, AdventOfCodeError> {
+    let mut rules: Vec<Rule> = vec![];
+    let mut updates: Vec<Vec<Page>> = vec![];
+    let mut section_parser = SectionParser::default();
+
+    for (line_index, line) in text.lines().enumerate() {
+        match section_parser.parse_line(line_index + 1, line)? {
+            Some(InputLine::Rule(rule)) => rules.push(rule),
+            Some(InputLine::Update(update)) => updates.push(update),
+            None => {}
         }
     }
 
     Ok((rules, updates))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AdventOfCodeError;
+    use crate::parse::parse_input;
+
+    #[test]
+    fn parses_sections() {
+        let input = "\
+47|53
+300|1000
+
+75,47,300
+1000
+";
+        let (rules, updates) = parse_input(input).unwrap();
+
+        assert_eq!(rules, vec![(47, 53), (300, 1000)]);
+        assert_eq!(updates, vec![vec![75, 47, 300], vec![1000]]);
+    }
+
+    #[test]
+    fn parses_windows_line_endings() {
+        let (rules, updates) = parse_input("1|2\r\n\r\n1,2\r\n").unwrap();
+
+        assert_eq!(rules, vec![(1, 2)]);
+        assert_eq!(updates, vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn reports_invalid_rule_with_line_number() {
+        let result = parse_input("1|2\n1,2\n\n1,2");
+
+        match result {
+            Err(AdventOfCodeError::InvalidRuleError { line_number, line }) => {
+                assert_eq!(line_number, 2);
+                assert_eq!(line, "1,2");
+            }
+            _ => panic!("Expected an invalid rule error"),
+        }
+        assert!(parse_input("1|2|3").is_err());
+    }
+
+    #[test]
+    fn reports_invalid_page_with_line_number() {
+        let result = parse_input("1|2\n\n1,2\n3,,4");
+
+        match result {
+            Err(AdventOfCodeError::ParseError {
+                line_number, line, ..
+            }) => {
+                assert_eq!(line_number, 4);
+                assert_eq!(line, "3,,4");
+            }
+            _ => panic!("Expected a parse error"),
+        }
+        assert!(parse_input("1|2\n\n1|2").is_err());
+        assert!(parse_input("1|x").is_err());
+    }
+}
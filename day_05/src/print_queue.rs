@@ -0,0 +1,253 @@
+use crate::common::{find_first_violation, Page};
+use crate::error::AdventOfCodeError;
+use crate::order_violation::OrderViolation;
+use crate::parse::{InputLine, SectionParser};
+use crate::rule_graph::RuleGraph;
+use std::io::BufRead;
+
+/// An update which was fed to the print queue together with the first violation found in it
+#[derive(Debug, PartialEq)]
+pub struct CheckedUpdate {
+    pub pages: Vec<Page>,
+    pub violation: Option<OrderViolation>,
+}
+
+impl CheckedUpdate {
+    pub fn is_correctly_ordered(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+/// Keeps the rules in memory and checks updates page by page while they arrive, so neither the
+/// whole input nor the whole update has to be known before a violation is reported
+#[derive(Debug, Default)]
+pub struct PrintQueue {
+    graph: RuleGraph,
+    current_update: Vec<Page>,
+    violation: Option<OrderViolation>,
+}
+
+impl PrintQueue {
+    /// Adds a rule at runtime. Pages of the unfinished update are checked again, because the new
+    /// rule may break or fix their order.
+    pub fn add_rule(&mut self, page: Page, follower: Page) {
+        self.graph.add_rule(page, follower);
+        self.violation = find_first_violation(&self.graph, &self.current_update);
+    }
+
+    /// Appends the page to the unfinished update
+    ///
+    /// Returns:
+    /// The violation caused by this page if it is the first one in the update, otherwise None
+    pub fn push_page(&mut self, page: Page) -> Option<&OrderViolation> {
+        self.current_update.push(page);
+
+        if self.violation.is_some() {
+            return None;
+        }
+
+        self.violation = find_first_violation(&self.graph, &self.current_update);
+
+        self.violation.as_ref()
+    }
+
+    /// Ends the unfinished update and starts a new one
+    ///
+    /// Returns:
+    /// The finished update with its first violation. A violation found by a later page may come
+    /// before the one reported while pushing pages, so the whole update is checked again.
+    pub fn finish_update(&mut self) -> CheckedUpdate {
+        let pages = std::mem::take(&mut self.current_update);
+        let violation = self
+            .violation
+            .take()
+            .and_then(|_| find_first_violation(&self.graph, &pages));
+
+        CheckedUpdate { pages, violation }
+    }
+
+    /// Checks a whole update at once, the unfinished update is finished first
+    ///
+    /// Returns:
+    /// The update with its first violation
+    pub fn check_update(&mut self, update: &[Page]) -> CheckedUpdate {
+        self.finish_update();
+
+        for page in update {
+            self.push_page(*page);
+        }
+
+        self.finish_update()
+    }
+}
+
+/// Reads the input line by line, adding rules to the queue and checking updates as soon as their
+/// line is read. The sections are split by the first blank line like in parse_input.
+///
+/// Returns:
+/// A Result containing nothing, or an error with the number of the first invalid line
+pub fn stream_updates<R: BufRead>(
+    reader: R,
+    queue: &mut PrintQueue,
+    mut on_update: impl FnMut(CheckedUpdate),
+) -> Result<(), AdventOfCodeError> {
+    let mut section_parser = SectionParser::default();
+
+    for (line_index, line) in reader.lines().enumerate() {
+        match section_parser.parse_line(line_index + 1, &line?)? {
+            Some(InputLine::Rule((page, follower))) => queue.add_rule(page, follower),
+            Some(InputLine::Update(update)) => on_update(queue.check_update(&update)),
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::find_first_violation;
+    use crate::order_violation::{OrderViolation, ViolationKind};
+    use crate::print_queue::{stream_updates, CheckedUpdate, PrintQueue};
+    use crate::rule_graph::RuleGraph;
+
+    const INPUT: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn reports_violation_as_soon_as_page_is_pushed() {
+        let mut queue = PrintQueue::default();
+        queue.add_rule(1, 2);
+        queue.add_rule(2, 3);
+        queue.add_rule(1, 3);
+
+        assert_eq!(queue.push_page(1), None);
+        assert_eq!(queue.push_page(3), None);
+        assert_eq!(
+            queue.push_page(2).cloned(),
+            Some(OrderViolation {
+                first_position: 1,
+                first_page: 3,
+                second_position: 2,
+                second_page: 2,
+                kind: ViolationKind::ContradictedRule,
+            })
+        );
+        assert_eq!(queue.push_page(4), None);
+        assert!(!queue.finish_update().is_correctly_ordered());
+        assert_eq!(queue.push_page(2), None);
+    }
+
+    #[test]
+    fn rechecks_unfinished_update_after_rule_is_added() {
+        let mut queue = PrintQueue::default();
+
+        assert!(queue.push_page(1).is_none());
+        assert_eq!(queue.push_page(2).unwrap().kind, ViolationKind::MissingRule);
+
+        queue.add_rule(1, 2);
+        assert!(queue.push_page(3).is_some());
+
+        queue.add_rule(1, 3);
+        queue.add_rule(2, 3);
+        assert_eq!(
+            queue.finish_update(),
+            CheckedUpdate {
+                pages: vec![1, 2, 3],
+                violation: None,
+            }
+        );
+    }
+
+    #[test]
+    fn checks_whole_updates() {
+        let mut queue = PrintQueue::default();
+        queue.add_rule(1, 2);
+        queue.push_page(2);
+
+        assert!(queue.check_update(&[1, 2]).is_correctly_ordered());
+        assert!(!queue.check_update(&[2, 1]).is_correctly_ordered());
+    }
+
+    #[test]
+    fn streams_example_updates() {
+        let mut queue = PrintQueue::default();
+        let mut checked_updates = vec![];
+
+        stream_updates(INPUT.as_bytes(), &mut queue, |update| {
+            checked_updates.push(update)
+        })
+        .unwrap();
+
+        assert_eq!(
+            checked_updates
+                .iter()
+                .map(|update| update.is_correctly_ordered())
+                .collect::<Vec<bool>>(),
+            vec![true, true, true, false, false, false]
+        );
+        assert_eq!(
+            checked_updates[4].violation,
+            Some(OrderViolation {
+                first_position: 1,
+                first_page: 13,
+                second_position: 2,
+                second_page: 29,
+                kind: ViolationKind::ContradictedRule,
+            })
+        );
+    }
+
+    #[test]
+    fn reports_same_first_violation_as_whole_update_check() {
+        // pages 2 and 3 break a rule first, but page 4 at the end breaks a rule with page 1,
+        // which comes first in the update
+        let rules = [(1, 2), (1, 3), (3, 2), (2, 4), (3, 4), (4, 1)];
+        let update = [1, 2, 3, 4];
+        let mut queue = PrintQueue::default();
+        for (page, follower) in rules {
+            queue.add_rule(page, follower);
+        }
+        let graph = RuleGraph::new(&rules);
+
+        let result = queue.check_update(&update).violation;
+
+        assert_eq!(result, find_first_violation(&graph, &update));
+        assert_eq!(result.unwrap().second_page, 4);
+    }
+
+    #[test]
+    fn reports_invalid_line_while_streaming() {
+        let mut queue = PrintQueue::default();
+        let result = stream_updates("1|2\n\n1,2\n1,x".as_bytes(), &mut queue, |_| {});
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,259 @@
+use crate::common::Page;
+use crate::error::AdventOfCodeError;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Taking a page out of the update and putting it back on another position
+#[derive(Debug, PartialEq)]
+pub struct PageMove {
+    pub page: Page,
+    /// Position of the page before the move
+    pub from_index: usize,
+    /// Position of the page after the move
+    pub to_index: usize,
+}
+
+impl fmt::Display for PageMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Move page {} from position {} to position {}",
+            self.page, self.from_index, self.to_index
+        )
+    }
+}
+
+/// Finds the longest sequence of pages of the original update which are already in the same
+/// relative order as in the fixed update. These pages never have to be moved.
+///
+/// Returns:
+/// A set of pages which can stay in place
+fn find_pages_in_place(original_update: &[Page], fixed_update: &[Page]) -> HashSet<Page> {
+    let fixed_positions: Vec<usize> = original_update
+        .iter()
+        .filter_map(|page| {
+            fixed_update
+                .iter()
+                .position(|fixed_page| fixed_page == page)
+        })
+        .collect();
+    // for every length of an increasing sequence, the index of the sequence end with the smallest
+    // fixed position, and for every index, the index of the previous element in its sequence
+    let mut sequence_ends: Vec<usize> = vec![];
+    let mut previous_indexes: Vec<Option<usize>> = vec![None; fixed_positions.len()];
+
+    for (index, fixed_position) in fixed_positions.iter().enumerate() {
+        let length = sequence_ends
+            .partition_point(|end_index| fixed_positions[*end_index] < *fixed_position);
+
+        if length > 0 {
+            previous_indexes[index] = Some(sequence_ends[length - 1]);
+        }
+
+        if length == sequence_ends.len() {
+            sequence_ends.push(index);
+        } else {
+            sequence_ends[length] = index;
+        }
+    }
+
+    let mut pages_in_place = HashSet::new();
+    let mut current_index = sequence_ends.last().copied();
+
+    while let Some(index) = current_index {
+        pages_in_place.insert(fixed_update[fixed_positions[index]]);
+        current_index = previous_indexes[index];
+    }
+
+    pages_in_place
+}
+
+/// Finds the position of the page in the update
+///
+/// Returns:
+/// A Result containing the position, or an error if the updates do not hold the same pages
+fn find_position(
+    update: &[Page],
+    page: Page,
+    original_update: &[Page],
+    fixed_update: &[Page],
+) -> Result<usize, AdventOfCodeError> {
+    update
+        .iter()
+        .position(|current_page| *current_page == page)
+        .ok_or_else(|| AdventOfCodeError::MismatchedUpdatesError {
+            original_update: original_update.to_vec(),
+            fixed_update: fixed_update.to_vec(),
+        })
+}
+
+/// Finds the smallest number of moves which turn the original update into the fixed update.
+/// Pages of both updates have to be the same and unique, like the ones of an update sorted by
+/// sort_update.
+///
+/// Returns:
+/// A Result containing a vector of moves which have to be applied one after another, or an error
+/// if the updates do not hold the same unique pages
+pub fn find_repair_moves(
+    original_update: &[Page],
+    fixed_update: &[Page],
+) -> Result<Vec<PageMove>, AdventOfCodeError> {
+    let unique_fixed_pages: HashSet<&Page> = fixed_update.iter().collect();
+
+    // with as many pages as the fixed update has unique pages, the original update holds the
+    // same pages as soon as each fixed page is found in it
+    if original_update.len() != fixed_update.len() || unique_fixed_pages.len() != fixed_update.len()
+    {
+        return Err(AdventOfCodeError::MismatchedUpdatesError {
+            original_update: original_update.to_vec(),
+            fixed_update: fixed_update.to_vec(),
+        });
+    }
+
+    let pages_in_place = find_pages_in_place(original_update, fixed_update);
+    let mut current_update = original_update.to_vec();
+    let mut moves = vec![];
+
+    // pages are moved in their fixed order right behind the page preceding them in the fixed
+    // update, which is always already on its final position
+    for (fixed_index, page) in fixed_update.iter().enumerate() {
+        if pages_in_place.contains(page) {
+            continue;
+        }
+
+        let from_index = find_position(&current_update, *page, original_update, fixed_update)?;
+        current_update.remove(from_index);
+
+        let to_index = match fixed_index {
+            0 => 0,
+            _ => {
+                find_position(
+                    &current_update,
+                    fixed_update[fixed_index - 1],
+                    original_update,
+                    fixed_update,
+                )? + 1
+            }
+        };
+        current_update.insert(to_index, *page);
+
+        moves.push(PageMove {
+            page: *page,
+            from_index,
+            to_index,
+        });
+    }
+
+    Ok(moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Page;
+    use crate::error::AdventOfCodeError;
+    use crate::repair_moves::{find_repair_moves, PageMove};
+
+    fn apply_moves(update: &[Page], moves: &[PageMove]) -> Vec<Page> {
+        let mut update = update.to_vec();
+
+        for page_move in moves {
+            assert_eq!(update[page_move.from_index], page_move.page);
+            update.remove(page_move.from_index);
+            update.insert(page_move.to_index, page_move.page);
+        }
+
+        update
+    }
+
+    #[test]
+    fn finds_repair_moves_for_example_updates() {
+        let result1 = find_repair_moves(&[75, 97, 47, 61, 53], &[97, 75, 47, 61, 53]).unwrap();
+        let result2 = find_repair_moves(&[61, 13, 29], &[61, 29, 13]).unwrap();
+        let result3 = find_repair_moves(&[97, 13, 75, 29, 47], &[97, 75, 47, 29, 13]).unwrap();
+
+        assert_eq!(
+            result1,
+            vec![PageMove {
+                page: 75,
+                from_index: 0,
+                to_index: 1
+            }]
+        );
+        assert_eq!(
+            result2,
+            vec![PageMove {
+                page: 13,
+                from_index: 1,
+                to_index: 2
+            }]
+        );
+        assert_eq!(result3.len(), 2);
+        assert_eq!(
+            apply_moves(&[97, 13, 75, 29, 47], &result3),
+            vec![97, 75, 47, 29, 13]
+        );
+    }
+
+    #[test]
+    fn finds_no_moves_for_correct_update() {
+        let result = find_repair_moves(&[1, 2, 3], &[1, 2, 3]).unwrap();
+
+        assert_eq!(result, vec![]);
+    }
+
+    #[test]
+    fn finds_minimal_moves_for_all_permutations() {
+        let fixed_update: Vec<Page> = vec![1, 2, 3, 4, 5];
+        let mut permutations: Vec<Vec<Page>> = vec![vec![]];
+
+        for _ in 0..fixed_update.len() {
+            permutations = permutations
+                .iter()
+                .flat_map(|permutation| {
+                    fixed_update
+                        .iter()
+                        .filter(|page| !permutation.contains(page))
+                        .map(|page| [permutation.clone(), vec![*page]].concat())
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+        }
+
+        for permutation in permutations {
+            let moves = find_repair_moves(&permutation, &fixed_update).unwrap();
+            // the longest increasing subsequence, computed by brute force over all subsets
+            let longest_in_place = (0..1 << permutation.len())
+                .map(|mask: usize| {
+                    permutation
+                        .iter()
+                        .enumerate()
+                        .filter(|(index, _)| mask & (1 << index) != 0)
+                        .map(|(_, page)| *page)
+                        .collect::<Vec<Page>>()
+                })
+                .filter(|subsequence| subsequence.windows(2).all(|pair| pair[0] < pair[1]))
+                .map(|subsequence| subsequence.len())
+                .max()
+                .unwrap();
+
+            assert_eq!(apply_moves(&permutation, &moves), fixed_update);
+            assert_eq!(moves.len(), permutation.len() - longest_in_place);
+        }
+    }
+
+    #[test]
+    fn rejects_updates_with_different_pages() {
+        assert!(matches!(
+            find_repair_moves(&[1, 2, 1], &[2, 1]),
+            Err(AdventOfCodeError::MismatchedUpdatesError { .. })
+        ));
+        assert!(matches!(
+            find_repair_moves(&[1, 1, 2], &[1, 2, 3]),
+            Err(AdventOfCodeError::MismatchedUpdatesError { .. })
+        ));
+        assert!(matches!(
+            find_repair_moves(&[1, 2, 3], &[1, 2, 2]),
+            Err(AdventOfCodeError::MismatchedUpdatesError { .. })
+        ));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::common::{Page, Rule};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Page ordering rules stored as a directed graph, where every page points to the set of pages
+/// which have to be printed after it
+#[derive(Debug, Default)]
+pub struct RuleGraph {
+    followers: HashMap<Page, HashSet<Page>>,
+}
+
+impl RuleGraph {
+    /// Builds the graph from parsed rules
+    pub fn new(rules: &[Rule]) -> Self {
+        let mut graph = RuleGraph::default();
+
+        for (page, follower) in rules.iter() {
+            graph.add_rule(*page, *follower);
+        }
+
+        graph
+    }
+
+    pub fn add_rule(&mut self, page: Page, follower: Page) {
+        self.followers.entry(page).or_default().insert(follower);
+    }
+
+    /// Checks if there is a rule that the page has to be printed before the follower
+    ///
+    /// Returns:
+    /// True if the rule "page|follower" exists
+    pub fn before(&self, page: Page, follower: Page) -> bool {
+        self.followers
+            .get(&page)
+            .is_some_and(|followers| followers.contains(&follower))
+    }
+
+    /// Gets all pages which have to be printed after the given page
+    ///
+    /// Returns:
+    /// A vector of followers sorted by page number, empty if there is no rule starting with the page
+    pub fn followers(&self, page: Page) -> Vec<Page> {
+        let mut followers: Vec<Page> = self
+            .followers
+            .get(&page)
+            .map(|followers| followers.iter().copied().collect())
+            .unwrap_or_default();
+        followers.sort();
+
+        followers
+    }
+
+    /// Gets all pages mentioned in any rule
+    ///
+    /// Returns:
+    /// A vector of pages sorted by page number
+    pub fn pages(&self) -> Vec<Page> {
+        let pages: BTreeSet<Page> = self
+            .followers
+            .iter()
+            .flat_map(|(page, followers)| followers.iter().chain([page]))
+            .copied()
+            .collect();
+
+        pages.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Page;
+    use crate::parse::parse_input;
+    use crate::rule_graph::RuleGraph;
+    use std::collections::HashSet;
+
+    const INPUT: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn creates_rule_graph() {
+        let (rules, _) = parse_input(INPUT).unwrap();
+        let result = RuleGraph::new(&rules);
+        let followers = |page: Page| -> HashSet<Page> {
+            [13, 29, 47, 53, 61, 75, 97]
+                .into_iter()
+                .filter(|follower| result.before(page, *follower))
+                .collect()
+        };
+
+        assert_eq!(followers(47), HashSet::from([53, 13, 61, 29]));
+        assert_eq!(followers(97), HashSet::from([13, 61, 47, 29, 53, 75]));
+        assert_eq!(followers(75), HashSet::from([29, 53, 47, 61, 13]));
+        assert_eq!(followers(61), HashSet::from([13, 53, 29]));
+        assert_eq!(followers(29), HashSet::from([13]));
+        assert_eq!(followers(53), HashSet::from([29, 13]));
+        assert_eq!(followers(13), HashSet::new());
+    }
+
+    #[test]
+    fn gets_followers_and_pages() {
+        let graph = RuleGraph::new(&[(3, 1), (3, 2), (2, 1), (5, 4)]);
+
+        assert_eq!(graph.followers(3), vec![1, 2]);
+        assert_eq!(graph.followers(1), vec![]);
+        assert_eq!(graph.pages(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn checks_page_order() {
+        let (rules, _) = parse_input(INPUT).unwrap();
+        let result = RuleGraph::new(&rules);
+
+        assert!(result.before(47, 53));
+        assert!(!result.before(53, 47));
+        assert!(!result.before(13, 97));
+        assert!(!result.before(10, 20));
+    }
+}
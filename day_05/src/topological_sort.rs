@@ -0,0 +1,369 @@
+use crate::common::Page;
+use crate::error::AdventOfCodeError;
+use crate::rule_graph::RuleGraph;
+use crate::validation::find_cycles;
+use std::collections::HashMap;
+
+/// Checks that no page is printed twice in the update
+///
+/// Returns:
+/// A Result containing the pages of the update, or an error with the first repeated page
+fn unique_pages(update: &[Page]) -> Result<Vec<Page>, AdventOfCodeError> {
+    let mut pages: Vec<Page> = vec![];
+
+    for (position, page) in update.iter().enumerate() {
+        if pages.contains(page) {
+            return Err(AdventOfCodeError::DuplicatePageError {
+                page: *page,
+                position,
+            });
+        }
+        pages.push(*page);
+    }
+
+    Ok(pages)
+}
+
+/// Counts for every page of the update how many other pages of the update have to be printed
+/// before it. Only rules between pages of the update are taken into account.
+fn count_predecessors(graph: &RuleGraph, pages: &[Page]) -> HashMap<Page, usize> {
+    pages
+        .iter()
+        .map(|page| {
+            let predecessors_count = pages
+                .iter()
+                .filter(|another_page| another_page != &page && graph.before(**another_page, *page))
+                .count();
+
+            (*page, predecessors_count)
+        })
+        .collect()
+}
+
+/// Takes the page out of the remaining pages and lowers the predecessors count of its followers
+fn remove_page(
+    graph: &RuleGraph,
+    page: Page,
+    remaining_pages: &mut Vec<Page>,
+    predecessors_counts: &mut HashMap<Page, usize>,
+) {
+    remaining_pages.retain(|remaining_page| *remaining_page != page);
+
+    for follower in remaining_pages.iter() {
+        if graph.before(page, *follower) {
+            *predecessors_counts.get_mut(follower).unwrap() -= 1;
+        }
+    }
+}
+
+/// Finds a cycle between the pages, which have to contain one because none of them can be
+/// printed first
+///
+/// Returns:
+/// Pages of the cycle with the smallest page sorted by page number, without the pages which only
+/// have to be printed after the cycle
+fn find_cycle(graph: &RuleGraph, pages: &[Page]) -> Vec<Page> {
+    let mut update_graph = RuleGraph::default();
+
+    for page in pages {
+        for follower in pages {
+            if graph.before(*page, *follower) {
+                update_graph.add_rule(*page, *follower);
+            }
+        }
+    }
+
+    find_cycles(&update_graph)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| pages.to_vec())
+}
+
+/// Orders pages of the update with Kahn's algorithm, using only rules between pages of the update
+///
+/// Returns:
+/// A Result containing the only valid order of the pages, or an error if a page is repeated,
+/// if the rules contain a cycle or if more than one order is valid. The latter error lists the pages which could be
+/// printed first at the position where the order stops being unique.
+pub fn sort_update(graph: &RuleGraph, update: &[Page]) -> Result<Vec<Page>, AdventOfCodeError> {
+    let mut remaining_pages = unique_pages(update)?;
+    let mut predecessors_counts = count_predecessors(graph, &remaining_pages);
+    let mut sorted_update: Vec<Page> = vec![];
+
+    while !remaining_pages.is_empty() {
+        let ready_pages: Vec<Page> = remaining_pages
+            .iter()
+            .filter(|page| predecessors_counts[page] == 0)
+            .copied()
+            .collect();
+
+        match ready_pages.as_slice() {
+            [] => {
+                return Err(AdventOfCodeError::CycleError(find_cycle(
+                    graph,
+                    &remaining_pages,
+                )))
+            }
+            [page] => {
+                sorted_update.push(*page);
+                remove_page(graph, *page, &mut remaining_pages, &mut predecessors_counts);
+            }
+            _ => {
+                return Err(AdventOfCodeError::AmbiguousOrderingError {
+                    position: sorted_update.len(),
+                    pages: ready_pages,
+                })
+            }
+        }
+    }
+
+    Ok(sorted_update)
+}
+
+/// Extends the ordering by every page which could be printed next, backtracking over Kahn's
+/// algorithm until the limit of orderings is reached
+fn collect_orderings(
+    graph: &RuleGraph,
+    remaining_pages: &[Page],
+    predecessors_counts: &HashMap<Page, usize>,
+    ordering: &mut Vec<Page>,
+    orderings: &mut Vec<Vec<Page>>,
+    limit: usize,
+) -> Result<(), AdventOfCodeError> {
+    if remaining_pages.is_empty() {
+        orderings.push(ordering.clone());
+        return Ok(());
+    }
+
+    let ready_pages: Vec<Page> = remaining_pages
+        .iter()
+        .filter(|page| predecessors_counts[page] == 0)
+        .copied()
+        .collect();
+
+    // the pages of a cycle are never ready, whichever pages were chosen before them
+    if ready_pages.is_empty() {
+        return Err(AdventOfCodeError::CycleError(find_cycle(
+            graph,
+            remaining_pages,
+        )));
+    }
+
+    for page in ready_pages {
+        if orderings.len() >= limit {
+            break;
+        }
+
+        let mut next_remaining_pages = remaining_pages.to_vec();
+        let mut next_predecessors_counts = predecessors_counts.clone();
+        remove_page(
+            graph,
+            page,
+            &mut next_remaining_pages,
+            &mut next_predecessors_counts,
+        );
+
+        ordering.push(page);
+        collect_orderings(
+            graph,
+            &next_remaining_pages,
+            &next_predecessors_counts,
+            ordering,
+            orderings,
+            limit,
+        )?;
+        ordering.pop();
+    }
+
+    Ok(())
+}
+
+/// Lists the valid orders of pages of the update, using only rules between pages of the update.
+/// The number of orders grows factorially with independent pages, so at most `limit` of them are
+/// listed.
+///
+/// Returns:
+/// A Result containing the first `limit` valid orders, where pages which could be printed at the
+/// same position are tried in the order of the update, or an error if a page is repeated or if
+/// the rules contain a cycle
+pub fn all_orderings(
+    graph: &RuleGraph,
+    update: &[Page],
+    limit: usize,
+) -> Result<Vec<Vec<Page>>, AdventOfCodeError> {
+    let remaining_pages = unique_pages(update)?;
+    let predecessors_counts = count_predecessors(graph, &remaining_pages);
+    let mut orderings: Vec<Vec<Page>> = vec![];
+
+    if limit > 0 {
+        collect_orderings(
+            graph,
+            &remaining_pages,
+            &predecessors_counts,
+            &mut vec![],
+            &mut orderings,
+            limit,
+        )?;
+    }
+
+    Ok(orderings)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AdventOfCodeError;
+    use crate::parse::parse_input;
+    use crate::rule_graph::RuleGraph;
+    use crate::topological_sort::{all_orderings, sort_update};
+
+    const INPUT: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn sorts_updates() {
+        let (rules, updates) = parse_input(INPUT).unwrap();
+        let graph = RuleGraph::new(&rules);
+
+        assert_eq!(
+            sort_update(&graph, &updates[0]).unwrap(),
+            vec![75, 47, 61, 53, 29]
+        );
+        assert_eq!(
+            sort_update(&graph, &updates[3]).unwrap(),
+            vec![97, 75, 47, 61, 53]
+        );
+        assert_eq!(sort_update(&graph, &updates[4]).unwrap(), vec![61, 29, 13]);
+        assert_eq!(
+            sort_update(&graph, &updates[5]).unwrap(),
+            vec![97, 75, 47, 29, 13]
+        );
+    }
+
+    #[test]
+    fn reports_cycle() {
+        // page 5 is printed after the cycle, but it is not a part of it
+        let graph = RuleGraph::new(&[(1, 2), (2, 3), (3, 1), (4, 1), (3, 5)]);
+        let result = sort_update(&graph, &[3, 5, 2, 1, 4]);
+
+        match result {
+            Err(AdventOfCodeError::CycleError(pages)) => assert_eq!(pages, vec![1, 2, 3]),
+            _ => panic!("Expected a cycle error"),
+        }
+    }
+
+    #[test]
+    fn reports_ambiguous_ordering() {
+        let graph = RuleGraph::new(&[(1, 2), (1, 3)]);
+        let result = sort_update(&graph, &[3, 2, 1]);
+
+        match result {
+            Err(AdventOfCodeError::AmbiguousOrderingError { position, pages }) => {
+                assert_eq!(position, 1);
+                assert_eq!(pages, vec![3, 2]);
+            }
+            _ => panic!("Expected an ambiguous ordering error"),
+        }
+    }
+
+    #[test]
+    fn reports_ambiguous_ordering_of_pages_without_rules_quickly() {
+        // enumerating all orders of these pages would take 20! steps
+        let graph = RuleGraph::default();
+        let update: Vec<u32> = (1..=20).collect();
+
+        match sort_update(&graph, &update) {
+            Err(AdventOfCodeError::AmbiguousOrderingError { position, pages }) => {
+                assert_eq!(position, 0);
+                assert_eq!(pages, update);
+            }
+            _ => panic!("Expected an ambiguous ordering error"),
+        }
+    }
+
+    #[test]
+    fn reports_repeated_page() {
+        let graph = RuleGraph::new(&[(1, 2), (2, 3)]);
+        let result = sort_update(&graph, &[3, 2, 3, 1]);
+
+        assert!(matches!(
+            result,
+            Err(AdventOfCodeError::DuplicatePageError {
+                page: 3,
+                position: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn lists_all_orderings_of_independent_pages() {
+        // page 1 goes first, pages 2 and 3 do not depend on each other
+        let graph = RuleGraph::new(&[(1, 2), (1, 3)]);
+        let result = all_orderings(&graph, &[3, 2, 1], 10).unwrap();
+
+        assert_eq!(result, vec![vec![1, 3, 2], vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn lists_orderings_up_to_limit() {
+        let graph = RuleGraph::default();
+
+        assert_eq!(all_orderings(&graph, &[1, 2, 3], 10).unwrap().len(), 6);
+        assert_eq!(
+            all_orderings(&graph, &[1, 2, 3], 3).unwrap(),
+            vec![vec![1, 2, 3], vec![1, 3, 2], vec![2, 1, 3]]
+        );
+
+        // enumerating all orders of these pages would take 20! steps
+        let update: Vec<u32> = (1..=20).collect();
+        assert_eq!(all_orderings(&graph, &update, 2).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn lists_single_ordering_of_sorted_update() {
+        let (rules, updates) = parse_input(INPUT).unwrap();
+        let graph = RuleGraph::new(&rules);
+
+        assert_eq!(
+            all_orderings(&graph, &updates[4], 10).unwrap(),
+            vec![vec![61, 29, 13]]
+        );
+    }
+
+    #[test]
+    fn reports_cycle_instead_of_orderings() {
+        let graph = RuleGraph::new(&[(1, 2), (2, 3), (3, 1), (4, 1)]);
+        let result = all_orderings(&graph, &[3, 2, 1, 4], 10);
+
+        match result {
+            Err(AdventOfCodeError::CycleError(pages)) => assert_eq!(pages, vec![1, 2, 3]),
+            _ => panic!("Expected a cycle error"),
+        }
+    }
+}
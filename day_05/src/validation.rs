@@ -0,0 +1,304 @@
+use crate::common::{Page, Rule};
+use crate::rule_graph::RuleGraph;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+/// Problems found in the puzzle input which would make the computed sums meaningless
+#[derive(Debug, Default, PartialEq)]
+pub struct ValidationReport {
+    /// Pairs of rules "a|b" and "b|a", each pair reported once as (a, b) by the first rule
+    pub contradictory_rules: Vec<Rule>,
+    /// Rules which appear in the input more than once, each reported once
+    pub duplicate_rules: Vec<Rule>,
+    /// Groups of pages which depend on each other through the rules, each sorted by page number
+    pub cycles: Vec<Vec<Page>>,
+    /// Pages printed in updates which are not mentioned in any rule, sorted by page number
+    pub pages_without_rules: Vec<Page>,
+    /// Indexes of updates with an even number of pages, which have no single middle page
+    pub even_length_updates: Vec<usize>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self == &ValidationReport::default()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            return writeln!(f, "The input is valid");
+        }
+
+        for (page, follower) in self.contradictory_rules.iter() {
+            writeln!(
+                f,
+                "Contradictory rules: {page}|{follower} and {follower}|{page}"
+            )?;
+        }
+        for (page, follower) in self.duplicate_rules.iter() {
+            writeln!(f, "Duplicate rule: {page}|{follower}")?;
+        }
+        for cycle in self.cycles.iter() {
+            writeln!(f, "Cycle between {} pages: {cycle:?}", cycle.len())?;
+        }
+        for page in self.pages_without_rules.iter() {
+            writeln!(f, "Page without rules: {page}")?;
+        }
+        for update_index in self.even_length_updates.iter() {
+            writeln!(f, "Update {update_index} has an even number of pages")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn find_contradictory_rules(rules: &[Rule]) -> Vec<Rule> {
+    let mut seen_rules: HashSet<Rule> = HashSet::new();
+    let mut contradictory_rules = vec![];
+
+    for (page, follower) in rules.iter().copied() {
+        if seen_rules.insert((page, follower))
+            && seen_rules.contains(&(follower, page))
+            && page != follower
+        {
+            contradictory_rules.push((follower, page));
+        }
+    }
+
+    contradictory_rules
+}
+
+fn find_duplicate_rules(rules: &[Rule]) -> Vec<Rule> {
+    let mut seen_rules: HashSet<Rule> = HashSet::new();
+    let mut duplicate_rules = vec![];
+
+    for (page, follower) in rules.iter().copied() {
+        if !seen_rules.insert((page, follower)) && !duplicate_rules.contains(&(page, follower)) {
+            duplicate_rules.push((page, follower));
+        }
+    }
+
+    duplicate_rules
+}
+
+/// State of Tarjan's algorithm for strongly connected components
+#[derive(Default)]
+struct ComponentSearch {
+    next_index: usize,
+    indexes: HashMap<Page, usize>,
+    low_links: HashMap<Page, usize>,
+    stack: Vec<Page>,
+    on_stack: HashSet<Page>,
+    components: Vec<Vec<Page>>,
+}
+
+impl ComponentSearch {
+    /// Gives the page the next index and puts it on the stack of the current components
+    fn enter(&mut self, page: Page) {
+        self.indexes.insert(page, self.next_index);
+        self.low_links.insert(page, self.next_index);
+        self.next_index += 1;
+        self.stack.push(page);
+        self.on_stack.insert(page);
+    }
+
+    /// Lowers the low link of the page if the other value is lower
+    fn lower_low_link(&mut self, page: Page, value: usize) {
+        if value < self.low_links[&page] {
+            self.low_links.insert(page, value);
+        }
+    }
+
+    /// Visits all pages reachable from the page. Pages being visited are kept on an explicit
+    /// stack instead of the call stack, so long chains of rules cannot overflow it.
+    fn visit(&mut self, graph: &RuleGraph, page: Page) {
+        // pages being visited together with their followers which are still to be visited
+        let mut visit_stack: Vec<(Page, Vec<Page>)> = vec![];
+
+        self.enter(page);
+        visit_stack.push((page, graph.followers(page).into_iter().rev().collect()));
+
+        while let Some((page, followers)) = visit_stack.last_mut() {
+            let page = *page;
+
+            if let Some(follower) = followers.pop() {
+                if !self.indexes.contains_key(&follower) {
+                    self.enter(follower);
+                    visit_stack.push((
+                        follower,
+                        graph.followers(follower).into_iter().rev().collect(),
+                    ));
+                } else if self.on_stack.contains(&follower) {
+                    self.lower_low_link(page, self.indexes[&follower]);
+                }
+
+                continue;
+            }
+
+            visit_stack.pop();
+            if let Some((parent, _)) = visit_stack.last() {
+                self.lower_low_link(*parent, self.low_links[&page]);
+            }
+
+            // the page is the root of a component, all pages above it on the stack belong to it
+            if self.low_links[&page] == self.indexes[&page] {
+                let mut component = vec![];
+
+                while let Some(component_page) = self.stack.pop() {
+                    self.on_stack.remove(&component_page);
+                    component.push(component_page);
+
+                    if component_page == page {
+                        break;
+                    }
+                }
+
+                component.sort();
+                self.components.push(component);
+            }
+        }
+    }
+}
+
+/// Finds groups of pages which depend on each other, so no order of them satisfies all rules
+///
+/// Returns:
+/// A vector of cycles sorted by their smallest page
+pub fn find_cycles(graph: &RuleGraph) -> Vec<Vec<Page>> {
+    let mut search = ComponentSearch::default();
+
+    for page in graph.pages() {
+        if !search.indexes.contains_key(&page) {
+            search.visit(graph, page);
+        }
+    }
+
+    let mut cycles: Vec<Vec<Page>> = search
+        .components
+        .into_iter()
+        .filter(|component| component.len() > 1 || graph.before(component[0], component[0]))
+        .collect();
+    cycles.sort();
+
+    cycles
+}
+
+/// Checks the rules and updates for contradictions, cycles, duplicates, pages without rules and
+/// updates without a middle page
+///
+/// Returns:
+/// A report of all found problems
+pub fn validate_input(rules: &[Rule], updates: &[Vec<Page>]) -> ValidationReport {
+    let graph = RuleGraph::new(rules);
+    let rule_pages: HashSet<Page> = graph.pages().into_iter().collect();
+    let pages_without_rules: BTreeSet<Page> = updates
+        .iter()
+        .flatten()
+        .filter(|page| !rule_pages.contains(page))
+        .copied()
+        .collect();
+    let even_length_updates = updates
+        .iter()
+        .enumerate()
+        .filter(|(_, update)| update.len() % 2 == 0)
+        .map(|(update_index, _)| update_index)
+        .collect();
+
+    ValidationReport {
+        contradictory_rules: find_contradictory_rules(rules),
+        duplicate_rules: find_duplicate_rules(rules),
+        cycles: find_cycles(&graph),
+        pages_without_rules: pages_without_rules.into_iter().collect(),
+        even_length_updates,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Rule;
+    use crate::parse::parse_input;
+    use crate::validation::{validate_input, ValidationReport};
+
+    const INPUT: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn accepts_valid_input() {
+        let (rules, updates) = parse_input(INPUT).unwrap();
+        let result = validate_input(&rules, &updates);
+
+        assert!(result.is_valid());
+        assert_eq!(result, ValidationReport::default());
+    }
+
+    #[test]
+    fn reports_all_problems() {
+        let input = "\
+1|2
+2|3
+3|1
+4|5
+5|4
+4|5
+6|6
+7|8
+
+1,2,3
+4,5
+7,9,8,10,9";
+        let (rules, updates) = parse_input(input).unwrap();
+        let result = validate_input(&rules, &updates);
+
+        assert!(!result.is_valid());
+        assert_eq!(
+            result,
+            ValidationReport {
+                contradictory_rules: vec![(4, 5)],
+                duplicate_rules: vec![(4, 5)],
+                cycles: vec![vec![1, 2, 3], vec![4, 5], vec![6]],
+                pages_without_rules: vec![9, 10],
+                even_length_updates: vec![1],
+            }
+        );
+    }
+
+    #[test]
+    fn finds_cycle_in_long_rule_chain() {
+        let pages_count = 100_000;
+        let mut rules: Vec<Rule> = (0..pages_count).map(|page| (page, page + 1)).collect();
+        rules.push((pages_count, 0));
+
+        let result = validate_input(&rules, &[]);
+
+        assert_eq!(result.cycles.len(), 1);
+        assert_eq!(result.cycles[0].len(), pages_count as usize + 1);
+    }
+}
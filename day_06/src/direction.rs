@@ -1,12 +1,74 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
 pub enum Direction {
     UP,
     DOWN,
     LEFT,
     RIGHT,
+    UP_RIGHT,
+    DOWN_RIGHT,
+    DOWN_LEFT,
+    UP_LEFT,
 }
 
 impl Direction {
-    pub fn default() -> Self {
-        Self::UP
+    /// Gets the direction of a guard standing on the map, only straight directions have a symbol
+    ///
+    /// Returns:
+    /// The direction the guard is facing, or None if the symbol is not a guard
+    pub fn from_guard_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '^' => Some(Self::UP),
+            '>' => Some(Self::RIGHT),
+            'v' => Some(Self::DOWN),
+            '<' => Some(Self::LEFT),
+            _ => None,
+        }
+    }
+
+    /// Gets the symbol of the guard facing this direction
+    pub fn guard_symbol(&self) -> char {
+        match self {
+            Self::UP => '^',
+            Self::RIGHT => '>',
+            Self::DOWN => 'v',
+            Self::LEFT => '<',
+            Self::UP_RIGHT | Self::DOWN_LEFT => '/',
+            Self::DOWN_RIGHT | Self::UP_LEFT => '\\',
+        }
+    }
+
+    /// Gets the change of (row, column) after one step in this direction
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Self::UP => (-1, 0),
+            Self::UP_RIGHT => (-1, 1),
+            Self::RIGHT => (0, 1),
+            Self::DOWN_RIGHT => (1, 1),
+            Self::DOWN => (1, 0),
+            Self::DOWN_LEFT => (1, -1),
+            Self::LEFT => (0, -1),
+            Self::UP_LEFT => (-1, -1),
+        }
+    }
+
+    /// Rotates the direction clockwise by the given number of eighths of a full turn
+    pub fn rotate(&self, eighths: usize) -> Self {
+        const CLOCKWISE: [Direction; 8] = [
+            Direction::UP,
+            Direction::UP_RIGHT,
+            Direction::RIGHT,
+            Direction::DOWN_RIGHT,
+            Direction::DOWN,
+            Direction::DOWN_LEFT,
+            Direction::LEFT,
+            Direction::UP_LEFT,
+        ];
+        let index = CLOCKWISE
+            .iter()
+            .position(|direction| direction == self)
+            .unwrap_or_default();
+
+        CLOCKWISE[(index + eighths) % CLOCKWISE.len()]
     }
 }
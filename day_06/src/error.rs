@@ -1,8 +1,42 @@
+use std::fmt;
 use std::io;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum AdventOfCodeError {
     IoError(io::Error),
+    // the guard came back to a position in the same direction, so the patrol never ends
+    GuardLoopError((isize, isize)),
+    NoGuardError,
+    // positions of all guards found in a map which allows only one
+    MultipleGuardsError(Vec<(isize, isize)>),
+    // a command line flag followed by a missing or invalid value
+    ArgumentError { flag: String, value: Option<String> },
+    // a command line flag which works only with a single guard used with a map of more guards
+    SingleGuardFlagError { flag: String, guards_count: usize },
+}
+
+impl fmt::Display for AdventOfCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdventOfCodeError::IoError(err) => write!(f, "Cannot read the input: {err}"),
+            AdventOfCodeError::GuardLoopError(position) => {
+                write!(f, "The guard is stuck in a loop at position {position:?}")
+            }
+            AdventOfCodeError::NoGuardError => write!(f, "There is no guard in the map"),
+            AdventOfCodeError::MultipleGuardsError(positions) => {
+                write!(f, "There is more than one guard in the map: {positions:?}")
+            }
+            AdventOfCodeError::ArgumentError { flag, value } => match value {
+                Some(value) => write!(f, "Invalid value \"{value}\" of flag {flag}"),
+                None => write!(f, "Flag {flag} expects a value"),
+            },
+            AdventOfCodeError::SingleGuardFlagError { flag, guards_count } => write!(
+                f,
+                "Flag {flag} supports only one guard, but the map has {guards_count} guards"
+            ),
+        }
+    }
 }
 
 impl From<io::Error> for AdventOfCodeError {
@@ -0,0 +1,66 @@
+use crate::direction::Direction;
+use std::str::FromStr;
+
+/// The way the guard changes direction in front of an obstacle
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TurnRule {
+    /// Turns right by 90 degrees, the rule of the original puzzle
+    #[default]
+    Right,
+    /// Turns left by 90 degrees
+    Left,
+    /// Turns around and walks back
+    Reverse,
+    /// Turns right by 45 degrees, so the guard walks diagonally after every other turn
+    Diagonal,
+}
+
+impl TurnRule {
+    /// Gets the direction of the guard after turning in front of an obstacle
+    pub fn turn(&self, direction: &Direction) -> Direction {
+        match self {
+            TurnRule::Right => direction.rotate(2),
+            TurnRule::Left => direction.rotate(6),
+            TurnRule::Reverse => direction.rotate(4),
+            TurnRule::Diagonal => direction.rotate(1),
+        }
+    }
+}
+
+impl FromStr for TurnRule {
+    type Err = ();
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "right" => Ok(TurnRule::Right),
+            "left" => Ok(TurnRule::Left),
+            "reverse" => Ok(TurnRule::Reverse),
+            "diagonal" => Ok(TurnRule::Diagonal),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The guard's starting state and patrol protocol
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Guard {
+    pub position: (isize, isize),
+    pub direction: Direction,
+    pub turn_rule: TurnRule,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::Direction;
+    use crate::guard::TurnRule;
+
+    #[test]
+    fn turns_by_rule() {
+        assert_eq!(TurnRule::Right.turn(&Direction::LEFT), Direction::UP);
+        assert_eq!(TurnRule::Left.turn(&Direction::UP), Direction::LEFT);
+        assert_eq!(TurnRule::Reverse.turn(&Direction::RIGHT), Direction::LEFT);
+        assert_eq!(TurnRule::Diagonal.turn(&Direction::UP_LEFT), Direction::UP);
+        assert_eq!("reverse".parse(), Ok(TurnRule::Reverse));
+        assert_eq!("around".parse::<TurnRule>(), Err(()));
+    }
+}
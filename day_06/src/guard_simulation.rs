@@ -0,0 +1,184 @@
+use crate::direction::Direction;
+use crate::guard::{Guard, TurnRule};
+use crate::map_traversal::{is_next_step_the_way_out, next_move_position, OBSTACLE_SYMBOL};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StepKind {
+    /// The guard stands on the starting position before the patrol begins
+    Start,
+    /// The guard stepped forward to a new position
+    Move,
+    /// The guard turned in place in front of an obstacle
+    Turn,
+}
+
+/// A single step of the patrol with the guard's state after the step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GuardStep {
+    pub position: (isize, isize),
+    pub direction: Direction,
+    pub kind: StepKind,
+}
+
+/// Simulates the guard's patrol step by step without changing the map. The iteration ends when
+/// the guard leaves the map or when a loop is found.
+pub struct GuardSimulation<'a> {
+    map: &'a [Vec<char>],
+    map_size: (usize, usize),
+    obstruction: Option<(isize, isize)>,
+    position: (isize, isize),
+    direction: Direction,
+    turn_rule: TurnRule,
+    visited_states: HashSet<((isize, isize), Direction)>,
+    is_started: bool,
+    is_finished: bool,
+    is_loop: bool,
+}
+
+impl<'a> GuardSimulation<'a> {
+    /// Creates the simulation of the guard in the map, optionally with one extra obstruction
+    pub fn new(map: &'a [Vec<char>], guard: &Guard, obstruction: Option<(isize, isize)>) -> Self {
+        GuardSimulation {
+            map,
+            map_size: (map.len(), map[0].len()),
+            obstruction,
+            position: guard.position,
+            direction: guard.direction,
+            turn_rule: guard.turn_rule,
+            visited_states: HashSet::from([(guard.position, guard.direction)]),
+            is_started: false,
+            is_finished: false,
+            is_loop: false,
+        }
+    }
+
+    /// Checks if the simulation ended because the guard came back to a position in the same
+    /// direction, which means the guard will walk in circles forever
+    pub fn is_loop(&self) -> bool {
+        self.is_loop
+    }
+}
+
+impl Iterator for GuardSimulation<'_> {
+    type Item = GuardStep;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.is_started {
+            self.is_started = true;
+
+            return Some(GuardStep {
+                position: self.position,
+                direction: self.direction,
+                kind: StepKind::Start,
+            });
+        }
+
+        if self.is_finished {
+            return None;
+        }
+
+        let next_possible_move_position = next_move_position(&self.direction, &self.position);
+
+        if is_next_step_the_way_out(self.map_size, &self.direction, next_possible_move_position) {
+            self.is_finished = true;
+
+            return None;
+        }
+
+        let next_character = self.map[next_possible_move_position.0 as usize]
+            [next_possible_move_position.1 as usize];
+        let kind = if next_character == OBSTACLE_SYMBOL
+            || self.obstruction == Some(next_possible_move_position)
+        {
+            self.direction = self.turn_rule.turn(&self.direction);
+            StepKind::Turn
+        } else {
+            self.position = next_possible_move_position;
+            StepKind::Move
+        };
+
+        // the same position in the same direction means the guard would repeat the same path
+        if !self.visited_states.insert((self.position, self.direction)) {
+            self.is_finished = true;
+            self.is_loop = true;
+
+            return None;
+        }
+
+        Some(GuardStep {
+            position: self.position,
+            direction: self.direction,
+            kind,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::Direction;
+    use crate::guard::TurnRule;
+    use crate::guard_simulation::{GuardSimulation, GuardStep, StepKind};
+    use crate::map_traversal::find_guard_in_the_map;
+    use crate::parse::parse_input;
+
+    #[test]
+    fn returns_steps_of_the_patrol() {
+        let map = parse_input(
+            "\
+.#.
+...
+.^.",
+        );
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+        let mut simulation = GuardSimulation::new(&map, &guard, None);
+        let result: Vec<GuardStep> = simulation.by_ref().collect();
+
+        assert_eq!(
+            result,
+            vec![
+                GuardStep {
+                    position: (2, 1),
+                    direction: Direction::UP,
+                    kind: StepKind::Start,
+                },
+                GuardStep {
+                    position: (1, 1),
+                    direction: Direction::UP,
+                    kind: StepKind::Move,
+                },
+                GuardStep {
+                    position: (1, 1),
+                    direction: Direction::RIGHT,
+                    kind: StepKind::Turn,
+                },
+                GuardStep {
+                    position: (1, 2),
+                    direction: Direction::RIGHT,
+                    kind: StepKind::Move,
+                },
+            ]
+        );
+        assert!(!simulation.is_loop());
+        assert_eq!(map[2][1], '^');
+    }
+
+    #[test]
+    fn stops_when_guard_is_in_a_loop() {
+        let map = parse_input(
+            "\
+.#...
+....#
+.^...
+#....
+...#.",
+        );
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+        let mut simulation = GuardSimulation::new(&map, &guard, None);
+        let steps_count = simulation.by_ref().count();
+
+        assert!(simulation.is_loop());
+        // the start, 7 moves around the loop and 4 turns
+        assert_eq!(steps_count, 12);
+    }
+}
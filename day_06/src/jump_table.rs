@@ -0,0 +1,250 @@
+use crate::direction::Direction;
+use crate::guard::Guard;
+use crate::map_traversal::OBSTACLE_SYMBOL;
+use std::collections::HashSet;
+
+/// Obstacles of the map grouped by straight and diagonal lines, so the guard can jump straight to
+/// the next obstacle in its direction instead of walking cell by cell
+#[derive(Debug)]
+pub struct JumpTable {
+    /// For every row, columns of its obstacles in ascending order
+    row_obstacles: Vec<Vec<isize>>,
+    /// For every column, rows of its obstacles in ascending order
+    column_obstacles: Vec<Vec<isize>>,
+    /// For every diagonal going down and right, rows of its obstacles in ascending order.
+    /// Diagonals are indexed by row - column shifted by the width of the map.
+    main_diagonal_obstacles: Vec<Vec<isize>>,
+    /// For every diagonal going down and left, rows of its obstacles in ascending order.
+    /// Diagonals are indexed by row + column.
+    anti_diagonal_obstacles: Vec<Vec<isize>>,
+    width: isize,
+}
+
+impl JumpTable {
+    pub fn new(map: &[Vec<char>]) -> Self {
+        let height = map.len();
+        let width = map[0].len();
+        let mut table = JumpTable {
+            row_obstacles: vec![vec![]; height],
+            column_obstacles: vec![vec![]; width],
+            main_diagonal_obstacles: vec![vec![]; height + width],
+            anti_diagonal_obstacles: vec![vec![]; height + width],
+            width: width as isize,
+        };
+
+        for (row_index, row) in map.iter().enumerate() {
+            for (column_index, symbol) in row.iter().enumerate() {
+                if *symbol == OBSTACLE_SYMBOL {
+                    table.insert_obstruction((row_index as isize, column_index as isize));
+                }
+            }
+        }
+
+        table
+    }
+
+    /// Gets the line of obstacles going through the position in the direction
+    ///
+    /// Returns:
+    /// Sorted coordinates of obstacles on the line and the coordinate of the position on it
+    fn line(&self, position: (isize, isize), direction: &Direction) -> (&Vec<isize>, isize) {
+        let (row_index, column_index) = position;
+
+        match direction {
+            Direction::LEFT | Direction::RIGHT => {
+                (&self.row_obstacles[row_index as usize], column_index)
+            }
+            Direction::UP | Direction::DOWN => {
+                (&self.column_obstacles[column_index as usize], row_index)
+            }
+            Direction::UP_LEFT | Direction::DOWN_RIGHT => (
+                &self.main_diagonal_obstacles[(row_index - column_index + self.width) as usize],
+                row_index,
+            ),
+            Direction::UP_RIGHT | Direction::DOWN_LEFT => (
+                &self.anti_diagonal_obstacles[(row_index + column_index) as usize],
+                row_index,
+            ),
+        }
+    }
+
+    /// Gets all lines going through the position together with the coordinate of the position
+    fn lines_mut(&mut self, position: (isize, isize)) -> [(&mut Vec<isize>, isize); 4] {
+        let (row_index, column_index) = position;
+
+        [
+            (&mut self.row_obstacles[row_index as usize], column_index),
+            (&mut self.column_obstacles[column_index as usize], row_index),
+            (
+                &mut self.main_diagonal_obstacles[(row_index - column_index + self.width) as usize],
+                row_index,
+            ),
+            (
+                &mut self.anti_diagonal_obstacles[(row_index + column_index) as usize],
+                row_index,
+            ),
+        ]
+    }
+
+    /// Adds an obstacle, the tables stay sorted. Adding an existing obstacle does nothing.
+    pub fn insert_obstruction(&mut self, position: (isize, isize)) {
+        for (line, coordinate) in self.lines_mut(position) {
+            if let Err(index) = line.binary_search(&coordinate) {
+                line.insert(index, coordinate);
+            }
+        }
+    }
+
+    /// Takes an obstacle away, removing a missing obstacle does nothing
+    pub fn remove_obstruction(&mut self, position: (isize, isize)) {
+        for (line, coordinate) in self.lines_mut(position) {
+            if let Ok(index) = line.binary_search(&coordinate) {
+                line.remove(index);
+            }
+        }
+    }
+
+    /// Finds where the guard stops in front of the next obstacle in its direction
+    ///
+    /// Returns:
+    /// The position in front of the obstacle, or None if there is no obstacle and the guard leaves
+    /// the map
+    pub fn jump(&self, position: (isize, isize), direction: &Direction) -> Option<(isize, isize)> {
+        let (obstacles, coordinate) = self.line(position, direction);
+        let offset = direction.offset();
+        // the coordinate along the line grows with the row, or with the column on horizontal lines
+        let step = if offset.0 != 0 { offset.0 } else { offset.1 };
+
+        let stop_coordinate = if step > 0 {
+            let index = obstacles.partition_point(|obstacle| *obstacle <= coordinate);
+            obstacles.get(index).map(|obstacle| obstacle - 1)
+        } else {
+            let index = obstacles.partition_point(|obstacle| *obstacle < coordinate);
+            (index > 0).then(|| obstacles[index - 1] + 1)
+        }?;
+        let distance = (stop_coordinate - coordinate).abs();
+
+        Some((
+            position.0 + offset.0 * distance,
+            position.1 + offset.1 * distance,
+        ))
+    }
+}
+
+/// Simulates the patrol by jumping from obstacle to obstacle. Every loop contains a turn, so only
+/// states right after turns have to be remembered.
+///
+/// Returns:
+/// True if the guard never leaves the map
+pub fn is_guard_in_a_loop(table: &JumpTable, guard: &Guard) -> bool {
+    let mut position = guard.position;
+    let mut direction = guard.direction;
+    let mut turn_states = HashSet::new();
+
+    while let Some(stop_position) = table.jump(position, &direction) {
+        position = stop_position;
+        direction = guard.turn_rule.turn(&direction);
+
+        if !turn_states.insert((position, direction)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::direction::Direction;
+    use crate::guard::TurnRule;
+    use crate::jump_table::{is_guard_in_a_loop, JumpTable};
+    use crate::map_traversal::{find_guard_in_the_map, patrol, PatrolOutcome};
+    use crate::parse::parse_input;
+
+    const INPUT: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn jumps_to_next_obstacle() {
+        let map = parse_input(INPUT);
+        let table = JumpTable::new(&map);
+
+        assert_eq!(table.jump((6, 4), &Direction::UP), Some((1, 4)));
+        assert_eq!(table.jump((1, 4), &Direction::RIGHT), Some((1, 8)));
+        assert_eq!(table.jump((1, 8), &Direction::DOWN), Some((6, 8)));
+        assert_eq!(table.jump((6, 8), &Direction::LEFT), Some((6, 2)));
+        assert_eq!(table.jump((7, 7), &Direction::DOWN), None);
+        assert_eq!(table.jump((5, 5), &Direction::LEFT), None);
+    }
+
+    #[test]
+    fn updates_table_with_temporary_obstruction() {
+        let map = parse_input(INPUT);
+        let mut table = JumpTable::new(&map);
+
+        table.insert_obstruction((7, 7));
+        assert_eq!(table.jump((5, 7), &Direction::DOWN), Some((6, 7)));
+        assert_eq!(table.jump((7, 2), &Direction::RIGHT), Some((7, 6)));
+
+        table.remove_obstruction((7, 7));
+        assert_eq!(table.jump((5, 7), &Direction::DOWN), None);
+        assert_eq!(table.jump((7, 2), &Direction::RIGHT), Some((7, 7)));
+    }
+
+    #[test]
+    fn jumps_diagonally() {
+        let map = parse_input(INPUT);
+        let table = JumpTable::new(&map);
+
+        assert_eq!(table.jump((3, 3), &Direction::UP_LEFT), None);
+        assert_eq!(table.jump((5, 4), &Direction::UP_LEFT), Some((4, 3)));
+        assert_eq!(table.jump((6, 5), &Direction::UP_RIGHT), Some((5, 6)));
+        assert_eq!(table.jump((6, 3), &Direction::UP_RIGHT), None);
+        assert_eq!(table.jump((2, 3), &Direction::DOWN_LEFT), Some((2, 3)));
+        assert_eq!(table.jump((5, 6), &Direction::DOWN_RIGHT), Some((6, 7)));
+    }
+
+    #[test]
+    fn detects_same_loops_as_step_by_step_patrol() {
+        let map = parse_input(INPUT);
+
+        for turn_rule in [
+            TurnRule::Right,
+            TurnRule::Left,
+            TurnRule::Reverse,
+            TurnRule::Diagonal,
+        ] {
+            let guard = find_guard_in_the_map(&map, turn_rule).unwrap();
+            let mut table = JumpTable::new(&map);
+
+            for row_index in 0..map.len() as isize {
+                for column_index in 0..map[0].len() as isize {
+                    let position = (row_index, column_index);
+
+                    // removing the obstruction again would remove an original obstacle too
+                    if position == guard.position
+                        || map[row_index as usize][column_index as usize] == '#'
+                    {
+                        continue;
+                    }
+
+                    table.insert_obstruction(position);
+                    assert_eq!(
+                        is_guard_in_a_loop(&table, &guard),
+                        patrol(&map, &guard, Some(position)) == PatrolOutcome::Loop
+                    );
+                    table.remove_obstruction(position);
+                }
+            }
+        }
+    }
+}
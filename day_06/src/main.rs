@@ -1,18 +1,156 @@
 use crate::error::AdventOfCodeError;
-use crate::map_traversal::number_of_moves_until_exit;
+use crate::guard::TurnRule;
+use crate::map_traversal::{find_guard_in_the_map, find_guards_in_the_map, get_patrol_stats};
+use crate::multiple_guards::{patrol_with_multiple_guards, CollisionKind};
+use crate::obstruction_placement::count_loop_obstruction_positions;
 use crate::parse::parse_input;
-use std::fs;
+use crate::replay::{render_final_frame, replay_patrol, ReplaySpeed};
+use std::time::Duration;
+use std::{env, fs, io};
 
 mod direction;
 mod error;
+mod guard;
+mod guard_simulation;
+mod jump_table;
 mod map_traversal;
+mod multiple_guards;
+mod obstruction_placement;
 mod parse;
+mod replay;
+
+// replays the guard's patrol frame by frame in the terminal
+const REPLAY_FLAG: &str = "--replay";
+// followed by a number of milliseconds to wait after each frame of the replay
+const DELAY_FLAG: &str = "--delay";
+// followed by a number of guard's steps shown by each frame of the replay
+const STEPS_PER_FRAME_FLAG: &str = "--steps-per-frame";
+// followed by a file path, exports the last frame of the patrol as text
+const EXPORT_FLAG: &str = "--export";
+// followed by "right", "left", "reverse" or "diagonal", the way the guard turns at obstacles
+const TURN_FLAG: &str = "--turn";
+const DEFAULT_DELAY_MILLISECONDS: u64 = 50;
+const DEFAULT_STEPS_PER_FRAME: usize = 1;
+
+/// Gets the value following the flag, or the default value if the flag is not used
+fn parse_flag_value<T: std::str::FromStr>(
+    args: &[String],
+    flag: &str,
+    default: T,
+) -> Result<T, AdventOfCodeError> {
+    let Some(flag_index) = args.iter().position(|arg| arg == flag) else {
+        return Ok(default);
+    };
+    let value = args.get(flag_index + 1);
+
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| AdventOfCodeError::ArgumentError {
+            flag: flag.to_string(),
+            value: value.cloned(),
+        })
+}
 
 fn main() -> Result<(), AdventOfCodeError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let replay = args.iter().any(|arg| arg == REPLAY_FLAG);
+    let export_path = args
+        .iter()
+        .any(|arg| arg == EXPORT_FLAG)
+        .then(|| parse_flag_value(&args, EXPORT_FLAG, String::new()))
+        .transpose()?;
+    let speed = ReplaySpeed {
+        frame_delay: Duration::from_millis(parse_flag_value(
+            &args,
+            DELAY_FLAG,
+            DEFAULT_DELAY_MILLISECONDS,
+        )?),
+        steps_per_frame: parse_flag_value(&args, STEPS_PER_FRAME_FLAG, DEFAULT_STEPS_PER_FRAME)?,
+    };
+
+    let turn_rule = parse_flag_value(&args, TURN_FLAG, TurnRule::default())?;
+
     let input = fs::read_to_string("./data/input.txt")?;
     let map = parse_input(input.as_str());
-    let moves_count = number_of_moves_until_exit(map);
+    let guards = find_guards_in_the_map(&map, turn_rule);
+
+    if guards.len() > 1 {
+        // the replay and the last frame show the patrol of a single guard
+        if let Some(flag) = [REPLAY_FLAG, EXPORT_FLAG]
+            .into_iter()
+            .find(|flag| args.iter().any(|arg| arg == flag))
+        {
+            return Err(AdventOfCodeError::SingleGuardFlagError {
+                flag: flag.to_string(),
+                guards_count: guards.len(),
+            });
+        }
+
+        let report = patrol_with_multiple_guards(&map, &guards);
+
+        for (guard_index, visited_positions) in report.visited_positions_by_guard.iter().enumerate()
+        {
+            println!(
+                "Guard {guard_index} at {:?} visits {} positions",
+                guards[guard_index].position,
+                visited_positions.len()
+            );
+        }
+        println!(
+            "Number of positions visited by any guard: {}",
+            report.visited_positions.len()
+        );
+        println!(
+            "Number of positions visited by more than one guard: {}",
+            report.shared_positions.len()
+        );
+        for collision in report.collisions.iter() {
+            match collision.kind {
+                CollisionKind::SamePosition => println!(
+                    "Guards {:?} collide at {:?} after {} steps",
+                    collision.guard_indexes, collision.position, collision.step_index
+                ),
+                CollisionKind::Swap { other_position } => println!(
+                    "Guards {:?} walk through each other between {:?} and {:?} after {} steps",
+                    collision.guard_indexes,
+                    other_position,
+                    collision.position,
+                    collision.step_index
+                ),
+            }
+        }
+        for guard_index in report.looping_guards.iter() {
+            println!("Guard {guard_index} is stuck in a loop");
+        }
+
+        return Ok(());
+    }
+
+    let guard = find_guard_in_the_map(&map, turn_rule)?;
+
+    if replay {
+        replay_patrol(&map, &guard, &speed, &mut io::stdout())?;
+    }
+
+    if let Some(path) = export_path {
+        fs::write(&path, render_final_frame(&map, &guard))?;
+        println!("Last frame of the patrol exported to {path}");
+    }
+
+    let loop_obstruction_positions_count = count_loop_obstruction_positions(&map, &guard)?;
+    let patrol_stats = get_patrol_stats(&map, &guard)?;
 
-    println!("Number of moves until guard gets out: {moves_count}");
+    println!(
+        "Number of moves until guard gets out: {}",
+        patrol_stats.visited_positions_count
+    );
+    println!(
+        "Length of the guard's path: {}, number of turns: {}",
+        patrol_stats.path_length, patrol_stats.turns_count
+    );
+    println!(
+        "Number of obstruction positions trapping guard in a loop: {}",
+        loop_obstruction_positions_count
+    );
     Ok(())
 }
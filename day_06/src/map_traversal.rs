@@ -1,92 +1,171 @@
 use crate::direction::Direction;
+use crate::error::AdventOfCodeError;
+use crate::guard::{Guard, TurnRule};
+use crate::guard_simulation::{GuardSimulation, StepKind};
+use std::collections::HashSet;
 
-const GUARD_SYMBOL: char = '^';
-const OBSTACLE_SYMBOL: char = '#';
-const VISITED_POSITION_SYMBOL: char = 'X';
+pub const OBSTACLE_SYMBOL: char = '#';
+
+/// Finds all guards in the map, their directions are given by the symbols '^', '>', 'v' and '<'
+///
+/// Returns:
+/// A vector of guards turning by the rule, ordered by rows and columns of their positions
+pub fn find_guards_in_the_map(map: &[Vec<char>], turn_rule: TurnRule) -> Vec<Guard> {
+    let mut guards = vec![];
 
-fn find_guard_in_the_map(map: &mut Vec<Vec<char>>) -> (isize, isize) {
     for (row_index, row) in map.iter().enumerate() {
         for (column_index, symbol) in row.iter().enumerate() {
-            if symbol == &GUARD_SYMBOL {
-                return (row_index as isize, column_index as isize);
+            if let Some(direction) = Direction::from_guard_symbol(*symbol) {
+                guards.push(Guard {
+                    position: (row_index as isize, column_index as isize),
+                    direction,
+                    turn_rule,
+                });
             }
         }
     }
 
-    (0, 0)
+    guards
 }
 
-fn change_guard_direction(direction: &Direction) -> Direction {
-    match direction {
-        Direction::UP => Direction::RIGHT,
-        Direction::DOWN => Direction::LEFT,
-        Direction::LEFT => Direction::UP,
-        Direction::RIGHT => Direction::DOWN,
+/// Finds the only guard in the map
+///
+/// Returns:
+/// A Result containing the guard turning by the rule, or an error if there is no guard or more
+/// than one guard in the map
+pub fn find_guard_in_the_map(
+    map: &[Vec<char>],
+    turn_rule: TurnRule,
+) -> Result<Guard, AdventOfCodeError> {
+    let guards = find_guards_in_the_map(map, turn_rule);
+
+    match guards.as_slice() {
+        [] => Err(AdventOfCodeError::NoGuardError),
+        [guard] => Ok(*guard),
+        _ => Err(AdventOfCodeError::MultipleGuardsError(
+            guards.iter().map(|guard| guard.position).collect(),
+        )),
     }
 }
 
-fn next_move_position(direction: &Direction, guard_position: &(isize, isize)) -> (isize, isize) {
-    match &direction {
-        Direction::UP => (guard_position.0 - 1, guard_position.1),
-        Direction::DOWN => (guard_position.0 + 1, guard_position.1),
-        Direction::LEFT => (guard_position.0, guard_position.1 - 1),
-        Direction::RIGHT => (guard_position.0, guard_position.1 + 1),
-    }
+pub fn next_move_position(
+    direction: &Direction,
+    guard_position: &(isize, isize),
+) -> (isize, isize) {
+    let offset = direction.offset();
+
+    (guard_position.0 + offset.0, guard_position.1 + offset.1)
 }
 
-fn is_next_step_the_way_out(
+pub fn is_next_step_the_way_out(
     map_size: (usize, usize),
     direction: &Direction,
     position_to_check: (isize, isize),
 ) -> bool {
-    match direction {
-        Direction::UP => position_to_check.0 == -1,
-        Direction::DOWN => position_to_check.0 == map_size.0 as isize,
-        Direction::LEFT => position_to_check.1 == -1,
-        Direction::RIGHT => position_to_check.1 == map_size.1 as isize,
-    }
+    let offset = direction.offset();
+    let is_row_out = match offset.0 {
+        -1 => position_to_check.0 == -1,
+        1 => position_to_check.0 == map_size.0 as isize,
+        _ => false,
+    };
+    let is_column_out = match offset.1 {
+        -1 => position_to_check.1 == -1,
+        1 => position_to_check.1 == map_size.1 as isize,
+        _ => false,
+    };
+
+    is_row_out || is_column_out
 }
 
-pub fn number_of_moves_until_exit(mut map: Vec<Vec<char>>) -> usize {
-    let map_size = (map.len(), map[0].len());
-    let mut visited_positions_count = 1;
-    let mut direction = Direction::default();
-    let mut guard_position = find_guard_in_the_map(&mut map);
-    let mut next_possible_move_position;
-    let mut next_character;
+/// The way the guard's patrol ends
+#[derive(Debug, PartialEq)]
+pub enum PatrolOutcome {
+    /// The guard left the map after visiting the given positions
+    Exit(HashSet<(isize, isize)>),
+    /// The guard came back to a position in the same direction and will walk in circles forever
+    Loop,
+}
 
-    // at the very beginning, we mark the guard's starting position as visited
-    map[guard_position.0 as usize][guard_position.1 as usize] = VISITED_POSITION_SYMBOL;
+/// Simulates the patrol without changing the map, optionally with one extra obstruction
+///
+/// Returns:
+/// The outcome of the patrol with all visited positions if the guard leaves the map
+pub fn patrol(
+    map: &[Vec<char>],
+    guard: &Guard,
+    obstruction: Option<(isize, isize)>,
+) -> PatrolOutcome {
+    let mut simulation = GuardSimulation::new(map, guard, obstruction);
+    let visited_positions = simulation.by_ref().map(|step| step.position).collect();
 
-    loop {
-        next_possible_move_position = next_move_position(&direction, &guard_position);
+    if simulation.is_loop() {
+        PatrolOutcome::Loop
+    } else {
+        PatrolOutcome::Exit(visited_positions)
+    }
+}
 
-        if is_next_step_the_way_out(map_size, &direction, next_possible_move_position) {
-            return visited_positions_count;
-        }
+/// Numbers describing the guard's path until the guard leaves the map
+#[derive(Debug, Default, PartialEq)]
+pub struct PatrolStats {
+    /// Distinct positions visited by the guard, including the starting position
+    pub visited_positions_count: usize,
+    /// Steps forward made by the guard
+    pub path_length: usize,
+    /// Turns made by the guard in front of obstacles
+    pub turns_count: usize,
+}
 
-        next_character =
-            map[next_possible_move_position.0 as usize][next_possible_move_position.1 as usize];
+/// Simulates the patrol without changing the map and counts its steps
+///
+/// Returns:
+/// A Result containing the stats of the patrol, or an error if the guard is stuck in a loop
+pub fn get_patrol_stats(
+    map: &[Vec<char>],
+    guard: &Guard,
+) -> Result<PatrolStats, AdventOfCodeError> {
+    let mut simulation = GuardSimulation::new(map, guard, None);
+    let mut visited_positions = HashSet::new();
+    let mut stats = PatrolStats::default();
+    let mut last_position = guard.position;
 
-        if next_character == OBSTACLE_SYMBOL {
-            direction = change_guard_direction(&direction);
-        } else {
-            guard_position = next_possible_move_position;
+    for step in simulation.by_ref() {
+        visited_positions.insert(step.position);
+        last_position = step.position;
 
-            if next_character != VISITED_POSITION_SYMBOL {
-                map[next_possible_move_position.0 as usize]
-                    [next_possible_move_position.1 as usize] = VISITED_POSITION_SYMBOL;
-                visited_positions_count += 1;
-            }
+        match step.kind {
+            StepKind::Start => {}
+            StepKind::Move => stats.path_length += 1,
+            StepKind::Turn => stats.turns_count += 1,
         }
     }
+
+    if simulation.is_loop() {
+        return Err(AdventOfCodeError::GuardLoopError(last_position));
+    }
+    stats.visited_positions_count = visited_positions.len();
+
+    Ok(stats)
+}
+
+/// The binary reads the number of moves from the stats, so the patrol is simulated only once
+#[cfg(test)]
+pub fn number_of_moves_until_exit(
+    map: &[Vec<char>],
+    guard: &Guard,
+) -> Result<usize, AdventOfCodeError> {
+    Ok(get_patrol_stats(map, guard)?.visited_positions_count)
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use crate::direction::Direction;
+    use crate::error::AdventOfCodeError;
+    use crate::guard::{Guard, TurnRule};
     use crate::map_traversal::{
-        find_guard_in_the_map, is_next_step_the_way_out, number_of_moves_until_exit,
+        find_guard_in_the_map, get_patrol_stats, is_next_step_the_way_out,
+        number_of_moves_until_exit, patrol, PatrolOutcome, PatrolStats,
     };
     use crate::parse::parse_input;
 
@@ -104,10 +183,40 @@ mod tests {
 
     #[test]
     fn gets_correct_initial_coordinates_of_guard() {
-        let mut map = parse_input(INPUT);
-        let result = find_guard_in_the_map(&mut map);
+        let map = parse_input(INPUT);
+        let result = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+
+        assert_eq!(result.position, (6, 4));
+        assert_eq!(result.direction, Direction::UP);
+    }
+
+    #[test]
+    fn gets_guard_direction_from_symbol() {
+        let map = parse_input("...\n..<\n...");
+        let result = find_guard_in_the_map(&map, TurnRule::Left).unwrap();
+
+        assert_eq!(
+            result,
+            Guard {
+                position: (1, 2),
+                direction: Direction::LEFT,
+                turn_rule: TurnRule::Left,
+            }
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_multiple_guards() {
+        let result1 = find_guard_in_the_map(&parse_input("..\n.#"), TurnRule::Right);
+        let result2 = find_guard_in_the_map(&parse_input(">.\n.v"), TurnRule::Right);
 
-        assert_eq!(result, (6, 4));
+        assert!(matches!(result1, Err(AdventOfCodeError::NoGuardError)));
+        match result2 {
+            Err(AdventOfCodeError::MultipleGuardsError(positions)) => {
+                assert_eq!(positions, vec![(0, 0), (1, 1)])
+            }
+            _ => panic!("Expected a multiple guards error"),
+        }
     }
 
     #[test]
@@ -142,13 +251,94 @@ mod tests {
         assert_eq!(result7, true);
         assert_eq!(result8, false);
         assert_eq!(result9, true);
+        // diagonal directions leave the map through any of the two borders
+        assert!(is_next_step_the_way_out(
+            map_size,
+            &Direction::UP_RIGHT,
+            (3, 10)
+        ));
+        assert!(is_next_step_the_way_out(
+            map_size,
+            &Direction::DOWN_LEFT,
+            (10, 3)
+        ));
+        assert!(!is_next_step_the_way_out(
+            map_size,
+            &Direction::UP_LEFT,
+            (3, 10)
+        ));
     }
 
     #[test]
     fn gets_correct_number_of_moves() {
         let map = parse_input(INPUT);
-        let result = number_of_moves_until_exit(map);
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+        let result = number_of_moves_until_exit(&map, &guard).unwrap();
 
         assert_eq!(result, 41);
     }
+
+    #[test]
+    fn gets_correct_patrol_stats() {
+        let map = parse_input(INPUT);
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+        let result = get_patrol_stats(&map, &guard).unwrap();
+
+        assert_eq!(
+            result,
+            PatrolStats {
+                visited_positions_count: 41,
+                path_length: 44,
+                turns_count: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn reports_guard_in_a_loop() {
+        let map = parse_input(
+            "\
+.#...
+....#
+.^...
+#....
+...#.",
+        );
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+        let result = number_of_moves_until_exit(&map, &guard);
+
+        assert!(matches!(result, Err(AdventOfCodeError::GuardLoopError(_))));
+        assert_eq!(patrol(&map, &guard, None), PatrolOutcome::Loop);
+    }
+
+    #[test]
+    fn patrols_with_and_without_obstruction() {
+        let map = parse_input(INPUT);
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+
+        match patrol(&map, &guard, None) {
+            PatrolOutcome::Exit(visited_positions) => assert_eq!(visited_positions.len(), 41),
+            PatrolOutcome::Loop => panic!("Expected the guard to leave the map"),
+        }
+        assert_eq!(patrol(&map, &guard, Some((6, 3))), PatrolOutcome::Loop);
+        assert_ne!(patrol(&map, &guard, Some((0, 0))), PatrolOutcome::Loop);
+    }
+
+    #[test]
+    fn patrols_with_other_turn_rules() {
+        let map = parse_input(INPUT);
+        let left_guard = find_guard_in_the_map(&map, TurnRule::Left).unwrap();
+        let reverse_guard = find_guard_in_the_map(&map, TurnRule::Reverse).unwrap();
+        let diagonal_guard = find_guard_in_the_map(&map, TurnRule::Diagonal).unwrap();
+
+        // turning left at (1, 4) leads straight out of the map
+        assert_eq!(number_of_moves_until_exit(&map, &left_guard).unwrap(), 10);
+        // walking back from (1, 4) down to the bottom border
+        assert_eq!(number_of_moves_until_exit(&map, &reverse_guard).unwrap(), 9);
+        // after turning at (1, 4) the guard walks diagonally up and right out of the map
+        assert_eq!(
+            number_of_moves_until_exit(&map, &diagonal_guard).unwrap(),
+            7
+        );
+    }
 }
@@ -0,0 +1,224 @@
+use crate::guard::Guard;
+use crate::guard_simulation::GuardSimulation;
+use std::collections::{HashMap, HashSet};
+
+/// The way guards meet each other
+#[derive(Debug, PartialEq)]
+pub enum CollisionKind {
+    /// The guards stand on the same position after the step
+    SamePosition,
+    /// Two guards swapped their positions in one step, so they walked through each other. The
+    /// other position is the one of the second guard after the step.
+    Swap { other_position: (isize, isize) },
+}
+
+/// Guards meeting each other after the same number of steps
+#[derive(Debug, PartialEq)]
+pub struct Collision {
+    /// Number of steps made by every guard, the starting positions are step 0
+    pub step_index: usize,
+    /// Position of the first colliding guard after the step
+    pub position: (isize, isize),
+    /// Indexes of the colliding guards in ascending order
+    pub guard_indexes: Vec<usize>,
+    pub kind: CollisionKind,
+}
+
+/// Result of the patrol of several guards in the same lab
+#[derive(Debug, Default, PartialEq)]
+pub struct MultiplePatrolsReport {
+    /// Positions visited by any guard
+    pub visited_positions: HashSet<(isize, isize)>,
+    /// Positions visited by each guard, in the order of the guards
+    pub visited_positions_by_guard: Vec<HashSet<(isize, isize)>>,
+    /// Positions visited by more than one guard, not necessarily at the same time
+    pub shared_positions: HashSet<(isize, isize)>,
+    /// Collisions ordered by their step
+    pub collisions: Vec<Collision>,
+    /// Indexes of guards which never leave the map
+    pub looping_guards: Vec<usize>,
+}
+
+/// Simulates patrols of all guards in lockstep, every guard makes one step (a move or a turn) at
+/// a time. Guards are not obstacles for each other, they walk through each other's positions.
+/// Guards swapping their positions in a step are reported as colliding too.
+/// A guard stops being simulated when it leaves the map or when its loop is found.
+///
+/// Returns:
+/// A report of visited positions and collisions of the guards
+pub fn patrol_with_multiple_guards(map: &[Vec<char>], guards: &[Guard]) -> MultiplePatrolsReport {
+    let mut simulations: Vec<GuardSimulation> = guards
+        .iter()
+        .map(|guard| GuardSimulation::new(map, guard, None))
+        .collect();
+    let mut report = MultiplePatrolsReport {
+        visited_positions_by_guard: vec![HashSet::new(); guards.len()],
+        ..MultiplePatrolsReport::default()
+    };
+    let mut active_guards: Vec<usize> = (0..guards.len()).collect();
+    let mut positions: Vec<(isize, isize)> = guards.iter().map(|guard| guard.position).collect();
+    let mut step_index = 0;
+
+    while !active_guards.is_empty() {
+        let mut guards_by_position: HashMap<(isize, isize), Vec<usize>> = HashMap::new();
+        // guards which changed their position in this step by their previous and new position
+        let mut guards_by_move = HashMap::new();
+
+        active_guards.retain(|guard_index| match simulations[*guard_index].next() {
+            Some(step) => {
+                report.visited_positions_by_guard[*guard_index].insert(step.position);
+                guards_by_position
+                    .entry(step.position)
+                    .or_default()
+                    .push(*guard_index);
+                if step.position != positions[*guard_index] {
+                    guards_by_move.insert((positions[*guard_index], step.position), *guard_index);
+                }
+                positions[*guard_index] = step.position;
+
+                true
+            }
+            None => {
+                if simulations[*guard_index].is_loop() {
+                    report.looping_guards.push(*guard_index);
+                }
+
+                false
+            }
+        });
+
+        let mut collisions: Vec<Collision> = guards_by_position
+            .into_iter()
+            .filter(|(_, guard_indexes)| guard_indexes.len() > 1)
+            .map(|(position, guard_indexes)| Collision {
+                step_index,
+                position,
+                guard_indexes,
+                kind: CollisionKind::SamePosition,
+            })
+            .collect();
+        for ((previous_position, position), guard_index) in guards_by_move.iter() {
+            match guards_by_move.get(&(*position, *previous_position)) {
+                // each swap is reported once, by the guard with the lower index
+                Some(other_guard_index) if guard_index < other_guard_index => {
+                    collisions.push(Collision {
+                        step_index,
+                        position: *position,
+                        guard_indexes: vec![*guard_index, *other_guard_index],
+                        kind: CollisionKind::Swap {
+                            other_position: *previous_position,
+                        },
+                    })
+                }
+                _ => {}
+            }
+        }
+        collisions.sort_by_key(|collision| collision.guard_indexes[0]);
+        report.collisions.extend(collisions);
+
+        step_index += 1;
+    }
+
+    report.looping_guards.sort();
+
+    let mut visits_count: HashMap<(isize, isize), usize> = HashMap::new();
+    for position in report.visited_positions_by_guard.iter().flatten() {
+        *visits_count.entry(*position).or_default() += 1;
+    }
+    report.visited_positions = visits_count.keys().copied().collect();
+    report.shared_positions = visits_count
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(position, _)| position)
+        .collect();
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::guard::TurnRule;
+    use crate::map_traversal::find_guards_in_the_map;
+    use crate::multiple_guards::{patrol_with_multiple_guards, Collision, CollisionKind};
+    use crate::parse::parse_input;
+    use std::collections::HashSet;
+
+    #[test]
+    fn patrols_with_multiple_guards() {
+        let map = parse_input(
+            "\
+.....
+>...<
+.....
+..^..",
+        );
+        let guards = find_guards_in_the_map(&map, TurnRule::Right);
+        let result = patrol_with_multiple_guards(&map, &guards);
+
+        assert_eq!(guards.len(), 3);
+        assert_eq!(
+            result.visited_positions_by_guard,
+            vec![
+                HashSet::from([(1, 0), (1, 1), (1, 2), (1, 3), (1, 4)]),
+                HashSet::from([(1, 4), (1, 3), (1, 2), (1, 1), (1, 0)]),
+                HashSet::from([(3, 2), (2, 2), (1, 2), (0, 2)]),
+            ]
+        );
+        assert_eq!(result.visited_positions.len(), 8);
+        assert_eq!(
+            result.shared_positions,
+            HashSet::from([(1, 0), (1, 1), (1, 2), (1, 3), (1, 4)])
+        );
+        assert_eq!(
+            result.collisions,
+            vec![Collision {
+                step_index: 2,
+                position: (1, 2),
+                guard_indexes: vec![0, 1, 2],
+                kind: CollisionKind::SamePosition,
+            }]
+        );
+        assert_eq!(result.looping_guards, vec![]);
+    }
+
+    #[test]
+    fn reports_looping_guards() {
+        let map = parse_input(
+            "\
+.#...
+....#
+.^..v
+#....
+...#.",
+        );
+        let guards = find_guards_in_the_map(&map, TurnRule::Right);
+        let result = patrol_with_multiple_guards(&map, &guards);
+
+        assert_eq!(result.looping_guards, vec![0]);
+        assert_eq!(result.visited_positions_by_guard[1].len(), 3);
+    }
+
+    #[test]
+    fn reports_guards_swapping_positions() {
+        let map = parse_input(
+            "\
+....
+.><.
+....",
+        );
+        let guards = find_guards_in_the_map(&map, TurnRule::Right);
+        let result = patrol_with_multiple_guards(&map, &guards);
+
+        assert_eq!(
+            result.collisions,
+            vec![Collision {
+                step_index: 1,
+                position: (1, 2),
+                guard_indexes: vec![0, 1],
+                kind: CollisionKind::Swap {
+                    other_position: (1, 1),
+                },
+            }]
+        );
+    }
+}
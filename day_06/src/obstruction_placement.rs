@@ -0,0 +1,64 @@
+use crate::error::AdventOfCodeError;
+use crate::guard::Guard;
+use crate::jump_table::{is_guard_in_a_loop, JumpTable};
+use crate::map_traversal::{patrol, PatrolOutcome};
+
+/// Counts positions where a single new obstruction makes the guard walk in a loop. Only positions
+/// on the guard's original path can change it, and the guard's starting position is excluded.
+/// Each obstruction is inserted into a jump table only for the time of its check.
+///
+/// Returns:
+/// A Result containing the number of positions, or an error if the guard is in a loop already
+pub fn count_loop_obstruction_positions(
+    map: &[Vec<char>],
+    guard: &Guard,
+) -> Result<usize, AdventOfCodeError> {
+    let visited_positions = match patrol(map, guard, None) {
+        PatrolOutcome::Exit(visited_positions) => visited_positions,
+        PatrolOutcome::Loop => return Err(AdventOfCodeError::GuardLoopError(guard.position)),
+    };
+
+    let mut table = JumpTable::new(map);
+    let loop_positions_count = visited_positions
+        .into_iter()
+        .filter(|position| *position != guard.position)
+        .filter(|position| {
+            table.insert_obstruction(*position);
+            let is_loop = is_guard_in_a_loop(&table, guard);
+            table.remove_obstruction(*position);
+
+            is_loop
+        })
+        .count();
+
+    Ok(loop_positions_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::guard::TurnRule;
+    use crate::map_traversal::find_guard_in_the_map;
+    use crate::obstruction_placement::count_loop_obstruction_positions;
+    use crate::parse::parse_input;
+
+    const INPUT: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn counts_loop_obstruction_positions() {
+        let map = parse_input(INPUT);
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+        let result = count_loop_obstruction_positions(&map, &guard).unwrap();
+
+        assert_eq!(result, 6);
+    }
+}
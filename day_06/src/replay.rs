@@ -0,0 +1,152 @@
+use crate::guard::Guard;
+use crate::guard_simulation::{GuardSimulation, GuardStep};
+use crate::map_traversal::OBSTACLE_SYMBOL;
+use std::collections::HashSet;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+const VISITED_POSITION_SYMBOL: char = 'X';
+const EMPTY_POSITION_SYMBOL: char = '.';
+// clears the terminal and moves the cursor to the top left corner
+const ANSI_CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// How fast the patrol is replayed
+#[derive(Debug)]
+pub struct ReplaySpeed {
+    /// Pause after every drawn frame
+    pub frame_delay: Duration,
+    /// Number of guard's steps between two drawn frames, at least 1
+    pub steps_per_frame: usize,
+}
+
+/// Renders the map with obstacles, positions visited so far and the guard facing its direction
+///
+/// Returns:
+/// A multiline string with the rendered map
+pub fn render_frame(
+    map: &[Vec<char>],
+    visited_positions: &HashSet<(isize, isize)>,
+    step: &GuardStep,
+) -> String {
+    let mut frame = String::new();
+
+    for (row_index, row) in map.iter().enumerate() {
+        for (column_index, symbol) in row.iter().enumerate() {
+            let position = (row_index as isize, column_index as isize);
+
+            if position == step.position {
+                frame.push(step.direction.guard_symbol());
+            } else if visited_positions.contains(&position) {
+                frame.push(VISITED_POSITION_SYMBOL);
+            } else if *symbol == OBSTACLE_SYMBOL {
+                frame.push(OBSTACLE_SYMBOL);
+            } else {
+                frame.push(EMPTY_POSITION_SYMBOL);
+            }
+        }
+
+        frame.push('\n');
+    }
+
+    frame
+}
+
+/// Replays the patrol frame by frame, the last frame is always drawn
+///
+/// Returns:
+/// A Result containing the last frame, or an error if the output cannot be written
+pub fn replay_patrol(
+    map: &[Vec<char>],
+    guard: &Guard,
+    speed: &ReplaySpeed,
+    output: &mut impl Write,
+) -> std::io::Result<String> {
+    let mut visited_positions = HashSet::new();
+    let mut frame = String::new();
+    let mut steps = GuardSimulation::new(map, guard, None)
+        .enumerate()
+        .peekable();
+
+    while let Some((step_index, step)) = steps.next() {
+        visited_positions.insert(step.position);
+
+        let is_last_step = steps.peek().is_none();
+
+        if step_index % speed.steps_per_frame.max(1) == 0 || is_last_step {
+            frame = render_frame(map, &visited_positions, &step);
+
+            write!(output, "{ANSI_CLEAR_SCREEN}{frame}")?;
+            output.flush()?;
+
+            if !is_last_step {
+                thread::sleep(speed.frame_delay);
+            }
+        }
+    }
+
+    // the simulation always starts with the guard's position, so the last frame is drawn
+    Ok(frame)
+}
+
+/// Renders the map after the whole patrol without drawing any intermediate frames
+///
+/// Returns:
+/// A multiline string with the last frame of the patrol
+pub fn render_final_frame(map: &[Vec<char>], guard: &Guard) -> String {
+    let mut visited_positions = HashSet::new();
+    let mut last_step = None;
+
+    for step in GuardSimulation::new(map, guard, None) {
+        visited_positions.insert(step.position);
+        last_step = Some(step);
+    }
+
+    last_step
+        .map(|step| render_frame(map, &visited_positions, &step))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::guard::TurnRule;
+    use crate::map_traversal::find_guard_in_the_map;
+    use crate::parse::parse_input;
+    use crate::replay::{render_final_frame, replay_patrol, ReplaySpeed, ANSI_CLEAR_SCREEN};
+    use std::time::Duration;
+
+    const INPUT: &str = "\
+.#..
+...#
+.^..
+....";
+
+    #[test]
+    fn renders_final_frame() {
+        let map = parse_input(INPUT);
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+        let result = render_final_frame(&map, &guard);
+
+        assert_eq!(result, ".#..\n.XX#\n.XX.\n..v.\n");
+    }
+
+    #[test]
+    fn replays_patrol_frame_by_frame() {
+        let map = parse_input(INPUT);
+        let guard = find_guard_in_the_map(&map, TurnRule::Right).unwrap();
+        let speed = ReplaySpeed {
+            frame_delay: Duration::ZERO,
+            steps_per_frame: 2,
+        };
+        let mut output = vec![];
+        let result = replay_patrol(&map, &guard, &speed, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let frames: Vec<&str> = output.split(ANSI_CLEAR_SCREEN).skip(1).collect();
+
+        // 7 steps drawn every second step, which includes the last one
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0], ".#..\n...#\n.^..\n....\n");
+        assert_eq!(frames[3], result);
+        assert_eq!(result, render_final_frame(&map, &guard));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::equation::Equation;
+use crate::operator::Operator;
+use crate::solver::solve_equation;
+
+/// Checks if operators placed between the numbers, evaluated strictly left to right, can produce
+/// the target of the equation
+///
+/// Returns:
+/// True if at least one combination of the operators produces the target
+pub fn is_equation_solvable(equation: &Equation, operators: &[Operator]) -> bool {
+    solve_equation(equation, operators).is_some()
+}
+
+/// Sums targets of all equations which can be solved with the operators
+pub fn get_total_calibration_result(equations: &[Equation], operators: &[Operator]) -> u64 {
+    equations
+        .iter()
+        .filter(|equation| is_equation_solvable(equation, operators))
+        .map(|equation| equation.target)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::calibration::{get_total_calibration_result, is_equation_solvable};
+    use crate::operator::{ALL_OPERATORS, BASIC_OPERATORS};
+    use crate::parse::parse_input;
+
+    const INPUT: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn finds_solvable_equations() {
+        let equations = parse_input(INPUT).unwrap();
+        let basic_result: Vec<bool> = equations
+            .iter()
+            .map(|equation| is_equation_solvable(equation, &BASIC_OPERATORS))
+            .collect();
+        let all_result: Vec<bool> = equations
+            .iter()
+            .map(|equation| is_equation_solvable(equation, &ALL_OPERATORS))
+            .collect();
+
+        assert_eq!(
+            basic_result,
+            vec![true, true, false, false, false, false, false, false, true]
+        );
+        assert_eq!(
+            all_result,
+            vec![true, true, false, true, true, false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn gets_total_calibration_result() {
+        let equations = parse_input(INPUT).unwrap();
+
+        assert_eq!(
+            get_total_calibration_result(&equations, &BASIC_OPERATORS),
+            3749
+        );
+        assert_eq!(
+            get_total_calibration_result(&equations, &ALL_OPERATORS),
+            11387
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::operator::Operator;
+
+/// A calibration equation with operators missing between its numbers
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equation {
+    pub target: u64,
+    pub numbers: Vec<u64>,
+}
+
+impl Equation {
+    /// Evaluates the numbers strictly left to right with the operators placed between them
+    ///
+    /// Returns:
+    /// The value of the left side, or None if the number of operators does not fit or a partial
+    /// result does not fit into u64
+    pub fn evaluate(&self, operators: &[Operator]) -> Option<u64> {
+        let (first_number, remaining_numbers) = self.numbers.split_first()?;
+
+        if operators.len() != remaining_numbers.len() {
+            return None;
+        }
+
+        remaining_numbers
+            .iter()
+            .zip(operators)
+            .try_fold(*first_number, |value, (number, operator)| {
+                operator.apply(value, *number)
+            })
+    }
+
+    /// Writes the equation with the operators placed between its numbers, like "292 = 11 + 6"
+    pub fn format_solution(&self, operators: &[Operator]) -> String {
+        let mut text = format!("{} =", self.target);
+
+        for (number_index, number) in self.numbers.iter().enumerate() {
+            if number_index > 0 {
+                if let Some(operator) = operators.get(number_index - 1) {
+                    text.push_str(&format!(" {operator}"));
+                }
+            }
+            text.push_str(&format!(" {number}"));
+        }
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equation::Equation;
+    use crate::operator::Operator;
+
+    #[test]
+    fn evaluates_left_to_right() {
+        let equation = Equation {
+            target: 7290,
+            numbers: vec![6, 8, 6, 15],
+        };
+        let operators = [
+            Operator::Multiply,
+            Operator::Concatenate,
+            Operator::Multiply,
+        ];
+
+        assert_eq!(equation.evaluate(&operators), Some(7290));
+        assert_eq!(equation.evaluate(&operators[1..]), None);
+        assert_eq!(
+            equation.format_solution(&operators),
+            "7290 = 6 * 8 || 6 * 15"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt;
+use std::io;
+use std::num;
+
+#[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum AdventOfCodeError {
+    IoError(io::Error),
+    ParseError {
+        line_number: usize,
+        line: String,
+        err: num::ParseIntError,
+    },
+    InvalidEquationError {
+        line_number: usize,
+        line: String,
+    },
+}
+
+impl fmt::Display for AdventOfCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdventOfCodeError::IoError(err) => write!(f, "Cannot read the input: {err}"),
+            AdventOfCodeError::ParseError {
+                line_number,
+                line,
+                err,
+            } => write!(
+                f,
+                "Cannot parse a number on line {line_number} \"{line}\": {err}"
+            ),
+            AdventOfCodeError::InvalidEquationError { line_number, line } => write!(
+                f,
+                "Line {line_number} \"{line}\" is not an equation in the format \"target: a b c\""
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for AdventOfCodeError {
+    fn from(err: io::Error) -> Self {
+        AdventOfCodeError::IoError(err)
+    }
+}
@@ -1,3 +1,73 @@
-fn main() {
-    println!("Welcome to Day 7!");
+use crate::calibration::get_total_calibration_result;
+use crate::error::AdventOfCodeError;
+use crate::operator::{ALL_OPERATORS, BASIC_OPERATORS};
+use crate::parse::parse_input;
+use crate::solver::{solve_equation, solve_equation_by_brute_force};
+use std::time::Instant;
+use std::{env, fs};
+
+mod calibration;
+mod equation;
+mod error;
+mod operator;
+mod parse;
+mod solver;
+
+// used when no other input path is given as the first argument, ./data/example.txt is the
+// example of the puzzle
+const INPUT_PATH: &str = "./data/input.txt";
+// prints operators solving every solvable equation
+const EXPLAIN_FLAG: &str = "--explain";
+// compares the time of the solver with the time of trying every combination of operators
+const BRUTE_FORCE_FLAG: &str = "--brute-force";
+
+fn main() -> Result<(), AdventOfCodeError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let explain = args.iter().any(|arg| arg == EXPLAIN_FLAG);
+    let brute_force = args.iter().any(|arg| arg == BRUTE_FORCE_FLAG);
+    let input_path = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .map_or(INPUT_PATH, |arg| arg.as_str());
+
+    let input = fs::read_to_string(input_path)?;
+    let equations = parse_input(&input)?;
+    let total_calibration_result = get_total_calibration_result(&equations, &BASIC_OPERATORS);
+    let total_calibration_result_with_concatenation =
+        get_total_calibration_result(&equations, &ALL_OPERATORS);
+
+    println!("Total calibration result: {total_calibration_result}");
+    println!(
+        "Total calibration result with concatenation: {total_calibration_result_with_concatenation}"
+    );
+
+    if explain {
+        println!("---------------------------------------");
+        for equation in equations.iter() {
+            if let Some(operators) = solve_equation(equation, &ALL_OPERATORS) {
+                println!("{}", equation.format_solution(&operators));
+            }
+        }
+    }
+
+    if brute_force {
+        println!("---------------------------------------");
+        for (name, solve) in [
+            ("Backward search", solve_equation as fn(_, _) -> _),
+            ("Brute force", solve_equation_by_brute_force),
+        ] {
+            let start = Instant::now();
+            let solved_count = equations
+                .iter()
+                .filter(|equation| solve(equation, &ALL_OPERATORS).is_some())
+                .count();
+
+            println!(
+                "{name}: {solved_count} equations solved in {:?}",
+                start.elapsed()
+            );
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,68 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Add,
+    Multiply,
+    /// Joins digits of the left and the right number, 12 || 345 is 12345
+    Concatenate,
+}
+
+/// Operators of the first part of the puzzle
+pub const BASIC_OPERATORS: [Operator; 2] = [Operator::Add, Operator::Multiply];
+/// Operators of the second part of the puzzle
+pub const ALL_OPERATORS: [Operator; 3] = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+
+impl Operator {
+    /// Applies the operator to the numbers
+    ///
+    /// Returns:
+    /// The result, or None if it does not fit into u64
+    pub fn apply(&self, left: u64, right: u64) -> Option<u64> {
+        match self {
+            Operator::Add => left.checked_add(right),
+            Operator::Multiply => left.checked_mul(right),
+            Operator::Concatenate => left
+                .checked_mul(10_u64.checked_pow(digits_count(right))?)?
+                .checked_add(right),
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operator::Add => write!(f, "+"),
+            Operator::Multiply => write!(f, "*"),
+            Operator::Concatenate => write!(f, "||"),
+        }
+    }
+}
+
+/// Counts decimal digits of the number, 0 has one digit
+pub fn digits_count(number: u64) -> u32 {
+    number.checked_ilog10().unwrap_or(0) + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::operator::{digits_count, Operator};
+
+    #[test]
+    fn applies_operators() {
+        assert_eq!(Operator::Add.apply(12, 345), Some(357));
+        assert_eq!(Operator::Multiply.apply(12, 345), Some(4140));
+        assert_eq!(Operator::Concatenate.apply(12, 345), Some(12345));
+        assert_eq!(Operator::Concatenate.apply(12, 0), Some(120));
+        assert_eq!(Operator::Multiply.apply(u64::MAX, 2), None);
+        assert_eq!(Operator::Concatenate.apply(u64::MAX / 10, 10), None);
+    }
+
+    #[test]
+    fn counts_digits() {
+        assert_eq!(digits_count(0), 1);
+        assert_eq!(digits_count(9), 1);
+        assert_eq!(digits_count(10), 2);
+        assert_eq!(digits_count(u64::MAX), 20);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::equation::Equation;
+use crate::error::AdventOfCodeError;
+
+const TARGET_SEPARATOR: &str = ":";
+
+fn parse_number(line_number: usize, line: &str, item: &str) -> Result<u64, AdventOfCodeError> {
+    item.trim()
+        .parse()
+        .map_err(|err| AdventOfCodeError::ParseError {
+            line_number,
+            line: line.to_string(),
+            err,
+        })
+}
+
+/// Parses an equation line in the format "target: a b c"
+///
+/// Returns:
+/// A Result containing the equation or an error with the line number if the line is not valid
+fn parse_equation(line_number: usize, line: &str) -> Result<Equation, AdventOfCodeError> {
+    let invalid_equation_error = || AdventOfCodeError::InvalidEquationError {
+        line_number,
+        line: line.to_string(),
+    };
+    let (target, numbers) = line
+        .split_once(TARGET_SEPARATOR)
+        .ok_or_else(invalid_equation_error)?;
+    let numbers: Vec<u64> = numbers
+        .split_whitespace()
+        .map(|item| parse_number(line_number, line, item))
+        .collect::<Result<_, _>>()?;
+
+    if numbers.is_empty() {
+        return Err(invalid_equation_error());
+    }
+
+    Ok(Equation {
+        target: parse_number(line_number, line, target)?,
+        numbers,
+    })
+}
+
+/// Parses all equations of the input, blank lines are skipped
+///
+/// Returns:
+/// A Result containing equations, or an error with the number of the first invalid line
+pub fn parse_input(text: &str) -> Result<Vec<Equation>, AdventOfCodeError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_index, line)| parse_equation(line_index + 1, line.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equation::Equation;
+    use crate::error::AdventOfCodeError;
+    use crate::parse::parse_input;
+
+    #[test]
+    fn parses_equations() {
+        let result = parse_input("190: 10 19\n\n3267: 81 40 27\n").unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                Equation {
+                    target: 190,
+                    numbers: vec![10, 19],
+                },
+                Equation {
+                    target: 3267,
+                    numbers: vec![81, 40, 27],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_lines() {
+        let result1 = parse_input("190: 10 19\n190 10 19");
+        let result2 = parse_input("190:");
+        let result3 = parse_input("190: 10 x");
+
+        assert!(matches!(
+            result1,
+            Err(AdventOfCodeError::InvalidEquationError { line_number: 2, .. })
+        ));
+        assert!(matches!(
+            result2,
+            Err(AdventOfCodeError::InvalidEquationError { line_number: 1, .. })
+        ));
+        assert!(matches!(
+            result3,
+            Err(AdventOfCodeError::ParseError { line_number: 1, .. })
+        ));
+    }
+}
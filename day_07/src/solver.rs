@@ -0,0 +1,233 @@
+use crate::equation::Equation;
+use crate::operator::{digits_count, Operator};
+
+/// Picks for every number the operator giving the smallest value so far. Every operator grows
+/// with its left number, so these operators give the smallest value of all and fit into u64
+/// whenever any operators do. It is needed when the remaining numbers are multiplied by zero and
+/// their value does not matter.
+fn find_smallest_operators(numbers: &[u64], operators: &[Operator]) -> Option<Vec<Operator>> {
+    let (first_number, remaining_numbers) = numbers.split_first()?;
+    let mut value = *first_number;
+    let mut solution = vec![];
+
+    for number in remaining_numbers {
+        let (operator, smallest_value) = operators
+            .iter()
+            .filter_map(|operator| Some((*operator, operator.apply(value, *number)?)))
+            .min_by_key(|(_, next_value)| *next_value)?;
+
+        value = smallest_value;
+        solution.push(operator);
+    }
+
+    Some(solution)
+}
+
+/// Undoes the last operator of the equation, the target of the remaining numbers is returned
+fn undo_operator(operator: &Operator, target: u64, last_number: u64) -> Option<u64> {
+    match operator {
+        Operator::Add => target.checked_sub(last_number),
+        Operator::Multiply => {
+            (last_number != 0 && target.is_multiple_of(last_number)).then(|| target / last_number)
+        }
+        Operator::Concatenate => {
+            let divisor = 10_u64.checked_pow(digits_count(last_number))?;
+
+            (target % divisor == last_number).then(|| target / divisor)
+        }
+    }
+}
+
+fn search_backwards(target: u64, numbers: &[u64], operators: &[Operator]) -> Option<Vec<Operator>> {
+    let (last_number, remaining_numbers) = numbers.split_last()?;
+
+    if remaining_numbers.is_empty() {
+        return (*last_number == target).then(Vec::new);
+    }
+
+    operators.iter().find_map(|operator| {
+        let mut solution = if *operator == Operator::Multiply && *last_number == 0 {
+            // anything multiplied by zero is zero
+            (target == 0).then(|| find_smallest_operators(remaining_numbers, operators))??
+        } else {
+            let remaining_target = undo_operator(operator, target, *last_number)?;
+
+            search_backwards(remaining_target, remaining_numbers, operators)?
+        };
+        solution.push(*operator);
+
+        Some(solution)
+    })
+}
+
+/// Works backwards from the target, undoing the last operator first. Addition is undone by
+/// subtraction, multiplication only if the target is divisible by the last number, and
+/// concatenation only if the target ends with digits of the last number. Branches which cannot
+/// be undone are pruned right away.
+///
+/// Returns:
+/// Operators placed between the numbers which produce the target, or None if there are none
+pub fn solve_equation(equation: &Equation, operators: &[Operator]) -> Option<Vec<Operator>> {
+    search_backwards(equation.target, &equation.numbers, operators)
+}
+
+fn find_operators_by_brute_force(
+    equation: &Equation,
+    operators: &[Operator],
+    is_solution: impl Fn(u64) -> bool,
+) -> Option<Vec<Operator>> {
+    let operators_count = equation.numbers.len().checked_sub(1)?;
+    let combinations_count = operators.len().checked_pow(operators_count as u32)?;
+
+    (0..combinations_count).find_map(|combination| {
+        // digits of the combination in base of the number of operators pick the operators
+        let sequence: Vec<Operator> = (0..operators_count)
+            .scan(combination, |remaining, _| {
+                let operator = operators[*remaining % operators.len()];
+                *remaining /= operators.len();
+
+                Some(operator)
+            })
+            .collect();
+
+        equation
+            .evaluate(&sequence)
+            .is_some_and(&is_solution)
+            .then_some(sequence)
+    })
+}
+
+/// Tries every combination of the operators, it is slow but simple enough to be a reference
+/// for solve_equation
+///
+/// Returns:
+/// Operators placed between the numbers which produce the target, or None if there are none
+pub fn solve_equation_by_brute_force(
+    equation: &Equation,
+    operators: &[Operator],
+) -> Option<Vec<Operator>> {
+    find_operators_by_brute_force(equation, operators, |value| value == equation.target)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equation::Equation;
+    use crate::operator::{Operator, ALL_OPERATORS, BASIC_OPERATORS};
+    use crate::solver::{solve_equation, solve_equation_by_brute_force};
+
+    #[test]
+    fn returns_operators_of_solution() {
+        let equation1 = Equation {
+            target: 292,
+            numbers: vec![11, 6, 16, 20],
+        };
+        let equation2 = Equation {
+            target: 192,
+            numbers: vec![17, 8, 14],
+        };
+
+        assert_eq!(
+            solve_equation(&equation1, &BASIC_OPERATORS),
+            Some(vec![Operator::Add, Operator::Multiply, Operator::Add])
+        );
+        assert_eq!(solve_equation(&equation2, &BASIC_OPERATORS), None);
+        assert_eq!(
+            solve_equation(&equation2, &ALL_OPERATORS),
+            Some(vec![Operator::Concatenate, Operator::Add])
+        );
+    }
+
+    #[test]
+    fn solves_equations_with_zeros() {
+        let equation1 = Equation {
+            target: 0,
+            numbers: vec![5, 7, 0],
+        };
+        let equation2 = Equation {
+            target: 50,
+            numbers: vec![5, 0],
+        };
+        let equation3 = Equation {
+            target: 5,
+            numbers: vec![0, 5],
+        };
+
+        assert_eq!(
+            solve_equation(&equation1, &BASIC_OPERATORS),
+            Some(vec![Operator::Add, Operator::Multiply])
+        );
+        assert_eq!(
+            solve_equation(&equation2, &ALL_OPERATORS),
+            Some(vec![Operator::Concatenate])
+        );
+        assert_eq!(
+            solve_equation(&equation3, &ALL_OPERATORS),
+            Some(vec![Operator::Add])
+        );
+    }
+
+    #[test]
+    fn solves_long_equations_multiplied_by_zero_quickly() {
+        // only multiplications by one keep the numbers before the zero within u64, trying all
+        // operators for them would take 3^40 steps
+        let mut numbers = vec![u64::MAX - 1];
+        numbers.extend([1; 40]);
+        numbers.push(0);
+        let equation = Equation { target: 0, numbers };
+        let result = solve_equation(&equation, &ALL_OPERATORS).unwrap();
+
+        assert_eq!(equation.evaluate(&result), Some(0));
+
+        // no operators keep the numbers before the zero within u64
+        let equation = Equation {
+            target: 0,
+            numbers: vec![u64::MAX, u64::MAX, 0],
+        };
+
+        assert_eq!(solve_equation(&equation, &ALL_OPERATORS), None);
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_equations() {
+        let mut rng = fastrand::Rng::with_seed(2024);
+
+        for _ in 0..5000 {
+            let numbers: Vec<u64> = (0..rng.usize(1..=6))
+                .map(|_| match rng.u8(0..10) {
+                    0 => 0,
+                    1 => rng.u64(u64::MAX / 1000..=u64::MAX),
+                    _ => rng.u64(1..=20),
+                })
+                .collect();
+            let mut equation = Equation {
+                target: rng.u64(0..=1000),
+                numbers,
+            };
+
+            // half of the equations get a target which is surely reachable
+            if rng.bool() {
+                let operators: Vec<Operator> = (1..equation.numbers.len())
+                    .map(|_| ALL_OPERATORS[rng.usize(0..ALL_OPERATORS.len())])
+                    .collect();
+
+                if let Some(target) = equation.evaluate(&operators) {
+                    equation.target = target;
+                }
+            }
+
+            for operators in [&BASIC_OPERATORS[..], &ALL_OPERATORS[..]] {
+                let result = solve_equation(&equation, operators);
+                let expected = solve_equation_by_brute_force(&equation, operators);
+
+                assert_eq!(result.is_some(), expected.is_some(), "{equation:?}");
+                if let Some(solution) = result {
+                    assert_eq!(
+                        equation.evaluate(&solution),
+                        Some(equation.target),
+                        "{equation:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,17 +1,24 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
 
 use crate::antenna::Antenna;
 
-const FREE_SPACE: char = '.';
+pub const FREE_SPACE: char = '.';
 
-#[derive(Debug)]
-enum AntidoteDirection {
-    FromAntenna1,
-    FromAntenna2,
-}
+/// Two antennas which produce antidotes together, of the same frequency unless the rule pairs
+/// antennas across frequencies
+pub type AntennaPair = (Antenna, Antenna);
+
+/// Positions of antidotes with all antenna pairs which produced each of them
+pub type Antidotes = HashMap<(isize, isize), Vec<AntennaPair>>;
+
+/// Antennas grouped by their frequency, ordered by the frequency character so that all reports
+/// iterate over frequencies in the same order
+pub type AntennasMap = BTreeMap<char, Vec<Antenna>>;
 
-pub fn create_antennas_map(map: &Vec<Vec<char>>) -> HashMap<char, Vec<Antenna>> {
-    let mut antennas_map: HashMap<char, Vec<Antenna>> = HashMap::new();
+pub fn create_antennas_map(map: &[Vec<char>]) -> AntennasMap {
+    let mut antennas_map = AntennasMap::new();
 
     for (row_index, row) in map.iter().enumerate() {
         for (column_index, character) in row.iter().enumerate() {
@@ -33,7 +40,7 @@ pub fn create_antennas_map(map: &Vec<Vec<char>>) -> HashMap<char, Vec<Antenna>>
     antennas_map
 }
 
-fn create_antenna_pairs(antennas: &Vec<Antenna>) -> Vec<(&Antenna, &Antenna)> {
+fn create_antenna_pairs(antennas: &[Antenna]) -> Vec<(&Antenna, &Antenna)> {
     let mut antenna_pairs: Vec<(&Antenna, &Antenna)> = Vec::new();
 
     for (index, first_antenna) in antennas.iter().enumerate() {
@@ -45,36 +52,25 @@ fn create_antenna_pairs(antennas: &Vec<Antenna>) -> Vec<(&Antenna, &Antenna)> {
     antenna_pairs
 }
 
-fn get_antidote_position(
-    pair: (&Antenna, &Antenna),
-    direction: AntidoteDirection,
-    row_difference: isize,
-    column_difference: isize,
-) -> (isize, isize) {
-    let (antenna1, antenna2) = pair;
-    let antidote_x;
-    let antidote_y;
-
-    match direction {
-        AntidoteDirection::FromAntenna1 => {
-            antidote_x = antenna1.row_index as isize - row_difference;
-            if antenna1.column_index <= antenna2.column_index {
-                antidote_y = antenna1.column_index as isize - column_difference;
-            } else {
-                antidote_y = antenna1.column_index as isize + column_difference;
-            }
-        }
-        AntidoteDirection::FromAntenna2 => {
-            antidote_x = antenna2.row_index as isize + row_difference;
-            if antenna1.column_index <= antenna2.column_index {
-                antidote_y = antenna2.column_index as isize + column_difference;
-            } else {
-                antidote_y = antenna2.column_index as isize - column_difference;
-            }
-        }
+/// Computes the greatest common divisor with the Euclidean algorithm, gcd(0, 0) is 0
+fn greatest_common_divisor(a: isize, b: isize) -> isize {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
     }
 
-    (antidote_x, antidote_y)
+    a
+}
+
+/// Gets the vector from the first antenna of the pair to the second one
+fn get_pair_difference(pair: (&Antenna, &Antenna)) -> (isize, isize) {
+    let (antenna1, antenna2) = pair;
+
+    (
+        antenna2.row_index as isize - antenna1.row_index as isize,
+        antenna2.column_index as isize - antenna1.column_index as isize,
+    )
 }
 
 fn is_antidote_in_map(antidote: (isize, isize), territory_dimension: (usize, usize)) -> bool {
@@ -85,120 +81,293 @@ fn is_antidote_in_map(antidote: (isize, isize), territory_dimension: (usize, usi
     is_antidote_x_in_map && is_antidote_y_in_map
 }
 
-fn get_antidote(
-    territory_dimension: (usize, usize),
-    pair: (&Antenna, &Antenna),
-    direction: AntidoteDirection,
-    row_difference: isize,
-    column_difference: isize,
-) -> Option<(isize, isize)> {
-    let antidote = get_antidote_position(pair, direction, row_difference, column_difference);
-
-    if is_antidote_in_map(antidote, territory_dimension) {
-        return Some(antidote);
+fn add_antidote(antidotes: &mut Antidotes, antidote: (isize, isize), pair: (&Antenna, &Antenna)) {
+    antidotes
+        .entry(antidote)
+        .or_default()
+        .push((pair.0.clone(), pair.1.clone()));
+}
+
+/// Where antidotes of an antenna pair lie on the line through both antennas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AntidotePlacement {
+    /// Antidotes where one antenna is `far` / `near` times as far from the antidote as the other
+    /// one, the puzzle's first part uses 1:2
+    Ratio { near: usize, far: usize },
+    /// Antidotes at every grid point in line with the antennas, including the antennas themselves
+    AnyGrid,
+}
+
+/// Reasons why a value of an antidote rule cannot be parsed
+#[derive(Debug, PartialEq)]
+pub enum RuleValueError {
+    /// The value is not written in any of the accepted formats
+    InvalidFormat,
+    /// Both parts of the ratio are zero, so the ratio describes no position
+    ZeroRatio,
+    /// The parts of the ratio are too large to be added together
+    TooLarge,
+}
+
+impl fmt::Display for RuleValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleValueError::InvalidFormat => write!(f, "the format is not valid"),
+            RuleValueError::ZeroRatio => write!(f, "both parts of the ratio are zero"),
+            RuleValueError::TooLarge => write!(f, "the parts of the ratio are too large"),
+        }
     }
+}
+
+impl FromStr for AntidotePlacement {
+    type Err = RuleValueError;
 
-    None
+    /// Parses "any" or a ratio written as "near:far", e.g. "1:3"
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text == "any" {
+            return Ok(AntidotePlacement::AnyGrid);
+        }
+
+        let (near, far) = text.split_once(':').ok_or(RuleValueError::InvalidFormat)?;
+        let (Ok(near), Ok(far)) = (near.parse::<usize>(), far.parse::<usize>()) else {
+            return Err(RuleValueError::InvalidFormat);
+        };
+        // the sum of both parts is used in computations with signed positions
+        let sum = near
+            .checked_add(far)
+            .and_then(|sum| isize::try_from(sum).ok())
+            .ok_or(RuleValueError::TooLarge)?;
+
+        match sum {
+            0 => Err(RuleValueError::ZeroRatio),
+            _ => Ok(AntidotePlacement::Ratio { near, far }),
+        }
+    }
 }
 
-pub fn calculate_antidotes_count(
-    antennas_map: &HashMap<char, Vec<Antenna>>,
-    territory_dimension: (usize, usize),
-) -> usize {
-    let mut unique_antidotes = HashSet::new();
-
-    for key in antennas_map.keys() {
-        let antennas = antennas_map.get(key).unwrap();
-        let antenna_pairs = create_antenna_pairs(antennas);
-
-        for pair in antenna_pairs {
-            let (antenna1, antenna2) = pair;
-            let row_difference = antenna1.row_index.abs_diff(antenna2.row_index) as isize;
-            let column_difference = antenna1.column_index.abs_diff(antenna2.column_index) as isize;
-
-            if let Some(antidote) = get_antidote(
-                territory_dimension,
-                pair,
-                AntidoteDirection::FromAntenna1,
-                row_difference,
-                column_difference,
-            ) {
-                unique_antidotes.insert(antidote);
+/// How many antidotes are placed beyond each antenna of a pair
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HarmonicsLimit {
+    /// At most this many antidotes, the n-th harmonic is n times as far from the nearer antenna
+    /// as the first one
+    Count(usize),
+    /// All antidotes up to the map boundaries
+    Unlimited,
+}
+
+impl FromStr for HarmonicsLimit {
+    type Err = RuleValueError;
+
+    /// Parses "all" or a number of harmonics
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "all" => Ok(HarmonicsLimit::Unlimited),
+            _ => text
+                .parse()
+                .map(HarmonicsLimit::Count)
+                .map_err(|_| RuleValueError::InvalidFormat),
+        }
+    }
+}
+
+/// Describes which positions become antidotes of an antenna pair
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AntidoteRule {
+    pub placement: AntidotePlacement,
+    /// Whether antidotes strictly between the two antennas count as well
+    pub include_inner: bool,
+    pub max_harmonics: HarmonicsLimit,
+    /// Whether antennas of different frequencies form pairs too
+    pub across_frequencies: bool,
+}
+
+/// The rule of the puzzle's first part
+pub const TWICE_AS_FAR_RULE: AntidoteRule = AntidoteRule {
+    placement: AntidotePlacement::Ratio { near: 1, far: 2 },
+    include_inner: false,
+    max_harmonics: HarmonicsLimit::Count(1),
+    across_frequencies: false,
+};
+
+/// The rule of the puzzle's second part, with resonant harmonics
+pub const ANY_GRID_RULE: AntidoteRule = AntidoteRule {
+    placement: AntidotePlacement::AnyGrid,
+    include_inner: true,
+    max_harmonics: HarmonicsLimit::Unlimited,
+    across_frequencies: false,
+};
+
+/// Gets the multiples of the pair's smallest grid step, measured from the first antenna, where
+/// antidotes of the pair lie. The second antenna is `divisor` steps away from the first one.
+///
+/// Returns:
+/// Step multiples of antidotes between the antennas and of the first harmonics beyond them, with
+/// the distance between two harmonics in steps. Harmonics too far to be represented are left out.
+fn get_antidote_steps(rule: &AntidoteRule, divisor: isize) -> (Vec<isize>, Option<isize>) {
+    match rule.placement {
+        AntidotePlacement::Ratio { near, far } => {
+            // the products below do not fit into isize for large ratios
+            let (near, far) = (near.min(far) as i128, near.max(far) as i128);
+            let divisor = divisor as i128;
+            let mut steps = vec![];
+
+            if rule.include_inner && near + far > 0 {
+                // the antidote splits the distance between the antennas in the ratio
+                for distance in [near, far] {
+                    if (distance * divisor) % (near + far) == 0 {
+                        steps.push((distance * divisor / (near + far)) as isize);
+                    }
+                }
             }
 
-            if let Some(antidote) = get_antidote(
-                territory_dimension,
-                pair,
-                AntidoteDirection::FromAntenna2,
-                row_difference,
-                column_difference,
-            ) {
-                unique_antidotes.insert(antidote);
+            // beyond the antennas the distance between them equals far - near parts
+            let harmonic_distance = (far > near && (near * divisor) % (far - near) == 0)
+                .then(|| isize::try_from(near * divisor / (far - near)).ok())
+                .flatten();
+
+            (steps, harmonic_distance)
+        }
+        AntidotePlacement::AnyGrid => {
+            let steps = if rule.include_inner {
+                (0..=divisor).collect()
+            } else {
+                vec![0, divisor]
+            };
+
+            (steps, Some(1))
+        }
+    }
+}
+
+/// Finds antidotes of a single antenna pair by the rule
+fn add_pair_antidotes(
+    antidotes: &mut Antidotes,
+    pair: (&Antenna, &Antenna),
+    rule: &AntidoteRule,
+    territory_dimension: (usize, usize),
+) {
+    let (antenna1, _) = pair;
+    let (row_difference, column_difference) = get_pair_difference(pair);
+    // the smallest step between two grid points on the line through both antennas
+    let divisor = greatest_common_divisor(row_difference, column_difference);
+    let step = (row_difference / divisor, column_difference / divisor);
+    // positions which overflow are far outside the map
+    let position = |multiple: isize| {
+        Some((
+            (antenna1.row_index as isize).checked_add(multiple.checked_mul(step.0)?)?,
+            (antenna1.column_index as isize).checked_add(multiple.checked_mul(step.1)?)?,
+        ))
+        .filter(|antidote| is_antidote_in_map(*antidote, territory_dimension))
+    };
+    let (mut steps, harmonic_distance) = get_antidote_steps(rule, divisor);
+
+    if let Some(harmonic_distance) = harmonic_distance {
+        // walk away from both antennas until both possible antidotes are outside the map
+        // boundaries, a zero distance keeps the antidotes at the antennas
+        let max_harmonics = match (harmonic_distance, rule.max_harmonics) {
+            (0, _) => 1,
+            (_, HarmonicsLimit::Count(count)) => isize::try_from(count).unwrap_or(isize::MAX),
+            (_, HarmonicsLimit::Unlimited) => isize::MAX,
+        };
+
+        for harmonic in 1..=max_harmonics {
+            let Some(distance) = harmonic.checked_mul(harmonic_distance) else {
+                break;
+            };
+            let before_first = -distance;
+            let after_second = divisor.saturating_add(distance);
+
+            if position(before_first).is_none() && position(after_second).is_none() {
+                break;
             }
+            steps.extend([before_first, after_second]);
         }
     }
 
-    unique_antidotes.len()
+    steps.sort();
+    steps.dedup();
+
+    for antidote in steps.into_iter().filter_map(position) {
+        add_antidote(antidotes, antidote, pair);
+    }
 }
 
-pub fn calculate_antidotes_at_any_grid_count(
-    antennas_map: &HashMap<char, Vec<Antenna>>,
+/// Finds antidotes of all antenna pairs by the rule
+///
+/// Returns:
+/// Antidotes inside the map with the antenna pairs which produced them
+pub fn find_antidotes_by_rule(
+    antennas_map: &AntennasMap,
     territory_dimension: (usize, usize),
-) -> usize {
-    let mut unique_antidotes = HashSet::new();
-
-    for key in antennas_map.keys() {
-        let antennas = antennas_map.get(key).unwrap();
-        let antenna_pairs = create_antenna_pairs(antennas);
-
-        for pair in antenna_pairs {
-            let (antenna1, antenna2) = pair;
-            let row_difference = antenna1.row_index.abs_diff(antenna2.row_index) as isize;
-            let column_difference = antenna1.column_index.abs_diff(antenna2.column_index) as isize;
-
-            // In the "at any grid" case, all antennas are also antidotes
-            unique_antidotes.insert((antenna1.row_index as isize, antenna1.column_index as isize));
-            unique_antidotes.insert((antenna2.row_index as isize, antenna2.column_index as isize));
-
-            let mut multiplier = 1_isize;
-            // continue increasing the row_difference and column_difference until a possible
-            // position of an antidote is outside the map boundaries
-            while let Some(antidote) = get_antidote(
-                territory_dimension,
-                pair,
-                AntidoteDirection::FromAntenna1,
-                row_difference * multiplier,
-                column_difference * multiplier,
-            ) {
-                unique_antidotes.insert(antidote);
-                multiplier += 1;
-            }
+    rule: &AntidoteRule,
+) -> Antidotes {
+    let mut unique_antidotes = Antidotes::new();
+    let antenna_groups: Vec<Vec<Antenna>> = if rule.across_frequencies {
+        vec![antennas_map.values().flatten().cloned().collect()]
+    } else {
+        antennas_map.values().cloned().collect()
+    };
 
-            multiplier = 1;
-            while let Some(antidote) = get_antidote(
-                territory_dimension,
-                pair,
-                AntidoteDirection::FromAntenna2,
-                row_difference * multiplier,
-                column_difference * multiplier,
-            ) {
-                unique_antidotes.insert(antidote);
-                multiplier += 1;
-            }
+    for antennas in antenna_groups.iter() {
+        for pair in create_antenna_pairs(antennas) {
+            add_pair_antidotes(&mut unique_antidotes, pair, rule, territory_dimension);
         }
     }
 
-    unique_antidotes.len()
+    unique_antidotes
+}
+
+/// Finds antidotes where one antenna of a pair is twice as far as the other one
+///
+/// Returns:
+/// Antidotes inside the map with the antenna pairs which produced them
+pub fn find_antidotes(
+    antennas_map: &AntennasMap,
+    territory_dimension: (usize, usize),
+) -> Antidotes {
+    find_antidotes_by_rule(antennas_map, territory_dimension, &TWICE_AS_FAR_RULE)
+}
+
+pub fn calculate_antidotes_count(
+    antennas_map: &AntennasMap,
+    territory_dimension: (usize, usize),
+) -> usize {
+    find_antidotes(antennas_map, territory_dimension).len()
+}
+
+/// Finds antidotes at every grid point in line with an antenna pair, including the antennas
+///
+/// Returns:
+/// Antidotes inside the map with the antenna pairs which produced them
+pub fn find_antidotes_at_any_grid(
+    antennas_map: &AntennasMap,
+    territory_dimension: (usize, usize),
+) -> Antidotes {
+    find_antidotes_by_rule(antennas_map, territory_dimension, &ANY_GRID_RULE)
+}
+
+pub fn calculate_antidotes_at_any_grid_count(
+    antennas_map: &AntennasMap,
+    territory_dimension: (usize, usize),
+) -> usize {
+    find_antidotes_at_any_grid(antennas_map, territory_dimension).len()
 }
 
 #[cfg(test)]
+#[allow(
+    non_snake_case,
+    clippy::bool_assert_comparison,
+    clippy::unnecessary_mut_passed
+)]
 mod tests {
     use crate::antenna::Antenna;
     use crate::antidote_counter::{
         calculate_antidotes_at_any_grid_count, calculate_antidotes_count, create_antenna_pairs,
-        create_antennas_map,
+        create_antennas_map, find_antidotes, find_antidotes_at_any_grid, find_antidotes_by_rule,
+        greatest_common_divisor, AntidotePlacement, AntidoteRule, HarmonicsLimit, RuleValueError,
+        ANY_GRID_RULE, TWICE_AS_FAR_RULE,
     };
-    use crate::parser::parse_input;
+    use crate::parser::{get_map_dimensions, parse_input};
 
     const INPUT: &str = "\
 ............
@@ -216,7 +385,7 @@ mod tests {
 
     #[test]
     fn gets_correct_antennas_map() {
-        let mut map = parse_input(INPUT);
+        let mut map = parse_input(INPUT).unwrap();
         let result = create_antennas_map(&mut map);
 
         assert_eq!(result.keys().count(), 2);
@@ -271,7 +440,7 @@ mod tests {
 
     #[test]
     fn gets_correct_antenna_pairs() {
-        let mut map = parse_input(INPUT);
+        let mut map = parse_input(INPUT).unwrap();
         let result = create_antennas_map(&mut map);
         let antenna_0_pairs = create_antenna_pairs(result.get(&'0').unwrap());
         let antenna_A_pairs = create_antenna_pairs(result.get(&'A').unwrap());
@@ -398,7 +567,7 @@ mod tests {
 
     #[test]
     fn gets_correct_antidotes_count() {
-        let mut map = parse_input(INPUT);
+        let mut map = parse_input(INPUT).unwrap();
         let map_dimensions = (map.len(), map[0].len());
         let antennas_map = create_antennas_map(&mut map);
         let result = calculate_antidotes_count(&antennas_map, map_dimensions);
@@ -408,11 +577,240 @@ mod tests {
 
     #[test]
     fn gets_correct_antidotes_at_any_grid_count() {
-        let mut map = parse_input(INPUT);
+        let mut map = parse_input(INPUT).unwrap();
         let map_dimensions = (map.len(), map[0].len());
         let antennas_map = create_antennas_map(&mut map);
         let result = calculate_antidotes_at_any_grid_count(&antennas_map, map_dimensions);
 
         assert_eq!(result, 34);
     }
+
+    #[test]
+    fn gets_greatest_common_divisor() {
+        assert_eq!(greatest_common_divisor(2, 4), 2);
+        assert_eq!(greatest_common_divisor(-6, 9), 3);
+        assert_eq!(greatest_common_divisor(0, -5), 5);
+        assert_eq!(greatest_common_divisor(7, 3), 1);
+    }
+
+    #[test]
+    fn gets_antidotes_between_antennas_at_any_grid() {
+        // the difference (2, 4) is not primitive, so (1, 2) lies on the line between the antennas
+        let map = parse_input(
+            "\
+a....
+.....
+....a
+.....
+.....",
+        )
+        .unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let result = calculate_antidotes_at_any_grid_count(&antennas_map, map_dimensions);
+
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn finds_antidotes_with_their_antenna_pairs() {
+        let map = parse_input(INPUT).unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let result = find_antidotes(&antennas_map, map_dimensions);
+        let antenna = |character, row_index, column_index| Antenna {
+            character,
+            row_index,
+            column_index,
+        };
+
+        assert_eq!(result.len(), 14);
+        // an antidote can be at the position of an antenna of another pair
+        assert_eq!(
+            result[&(5, 6)],
+            vec![(antenna('0', 1, 8), antenna('0', 3, 7))]
+        );
+        assert_eq!(
+            result[&(0, 11)],
+            vec![(antenna('0', 1, 8), antenna('0', 2, 5))]
+        );
+        // pairs of both frequencies produce the antidote at (1, 3)
+        assert_eq!(result[&(1, 3)].len(), 2);
+        assert!(result[&(1, 3)].contains(&(antenna('0', 2, 5), antenna('0', 3, 7))));
+        assert!(result[&(1, 3)].contains(&(antenna('A', 5, 6), antenna('A', 9, 9))));
+    }
+
+    #[test]
+    fn finds_each_antidote_once_per_pair_at_any_grid() {
+        let map = parse_input(INPUT).unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let result = find_antidotes_at_any_grid(&antennas_map, map_dimensions);
+
+        assert_eq!(result.len(), 34);
+        for pairs in result.values() {
+            for (pair_index, pair) in pairs.iter().enumerate() {
+                assert!(!pairs[pair_index + 1..].contains(pair));
+            }
+        }
+    }
+
+    #[test]
+    fn finds_antidotes_by_custom_ratio() {
+        let map = parse_input(
+            "\
+a........
+.........
+.........
+.........
+....a....
+.........
+.........
+.........
+.........",
+        )
+        .unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let rule = AntidoteRule {
+            placement: AntidotePlacement::Ratio { near: 1, far: 3 },
+            include_inner: true,
+            max_harmonics: HarmonicsLimit::Count(2),
+            across_frequencies: false,
+        };
+        let mut result: Vec<(isize, isize)> =
+            find_antidotes_by_rule(&antennas_map, map_dimensions, &rule)
+                .into_keys()
+                .collect();
+        result.sort();
+
+        // (1, 1) and (3, 3) lie between the antennas, (6, 6) and (8, 8) are the first two
+        // harmonics beyond the second antenna
+        assert_eq!(result, vec![(1, 1), (3, 3), (6, 6), (8, 8)]);
+
+        let rule = AntidoteRule {
+            max_harmonics: HarmonicsLimit::Unlimited,
+            include_inner: false,
+            ..rule
+        };
+        assert_eq!(
+            find_antidotes_by_rule(&antennas_map, map_dimensions, &rule).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn finds_inner_antidotes_of_first_part_rule() {
+        let map = parse_input("a...\n....\n....\n...a").unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let rule = AntidoteRule {
+            include_inner: true,
+            ..TWICE_AS_FAR_RULE
+        };
+        let mut result: Vec<(isize, isize)> =
+            find_antidotes_by_rule(&antennas_map, map_dimensions, &rule)
+                .into_keys()
+                .collect();
+        result.sort();
+
+        assert_eq!(result, vec![(1, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn limits_harmonics_at_any_grid() {
+        let map = parse_input(INPUT).unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let rule = AntidoteRule {
+            max_harmonics: HarmonicsLimit::Count(1),
+            ..ANY_GRID_RULE
+        };
+        let limited = find_antidotes_by_rule(&antennas_map, map_dimensions, &rule);
+        let twice_as_far = find_antidotes(&antennas_map, map_dimensions);
+
+        // all pairs of the example are primitive, so the first harmonics are the first part's
+        // antidotes and the rest are the antennas, one of which is at an antidote already
+        assert_eq!(limited.len(), 20);
+        assert!(twice_as_far
+            .keys()
+            .all(|antidote| limited.contains_key(antidote)));
+    }
+
+    #[test]
+    fn pairs_antennas_across_frequencies() {
+        let map = parse_input("a..\n.b.\n...").unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let rule = AntidoteRule {
+            across_frequencies: true,
+            ..TWICE_AS_FAR_RULE
+        };
+
+        assert_eq!(calculate_antidotes_count(&antennas_map, map_dimensions), 0);
+        assert_eq!(
+            find_antidotes_by_rule(&antennas_map, map_dimensions, &rule)
+                .into_keys()
+                .collect::<Vec<(isize, isize)>>(),
+            vec![(2, 2)]
+        );
+    }
+
+    #[test]
+    fn parses_antidote_placement() {
+        assert_eq!("any".parse(), Ok(AntidotePlacement::AnyGrid));
+        assert_eq!(
+            "1:3".parse(),
+            Ok(AntidotePlacement::Ratio { near: 1, far: 3 })
+        );
+        assert_eq!(
+            "0:0".parse::<AntidotePlacement>(),
+            Err(RuleValueError::ZeroRatio)
+        );
+        assert_eq!(
+            "2".parse::<AntidotePlacement>(),
+            Err(RuleValueError::InvalidFormat)
+        );
+        assert_eq!(
+            "18446744073709551615:1".parse::<AntidotePlacement>(),
+            Err(RuleValueError::TooLarge)
+        );
+        assert_eq!(
+            "9223372036854775807:1".parse::<AntidotePlacement>(),
+            Err(RuleValueError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn parses_harmonics_limit() {
+        assert_eq!("all".parse(), Ok(HarmonicsLimit::Unlimited));
+        assert_eq!("3".parse(), Ok(HarmonicsLimit::Count(3)));
+        assert_eq!(
+            "-1".parse::<HarmonicsLimit>(),
+            Err(RuleValueError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn skips_antidotes_of_huge_ratios() {
+        let map = parse_input("a...\n....\n....\n...a").unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+
+        // the first harmonic lies far outside the map, for the larger ratio its distance does not
+        // even fit into isize
+        for near in [1 << 61, 1 << 62] {
+            let rule = AntidoteRule {
+                placement: AntidotePlacement::Ratio {
+                    near,
+                    far: near + 1,
+                },
+                include_inner: true,
+                max_harmonics: HarmonicsLimit::Unlimited,
+                across_frequencies: false,
+            };
+
+            assert!(find_antidotes_by_rule(&antennas_map, map_dimensions, &rule).is_empty());
+        }
+    }
 }
@@ -1,8 +1,64 @@
+use crate::antidote_counter::RuleValueError;
+use std::fmt;
 use std::io;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum AdventOfCodeError {
     IoError(io::Error),
+    // a command line flag followed by an invalid value
+    ArgumentError {
+        flag: String,
+        value: String,
+        err: RuleValueError,
+    },
+    // a command line flag which is not followed by a value
+    MissingArgumentError {
+        flag: String,
+    },
+    EmptyMapError,
+    RaggedLineError {
+        line_number: usize,
+        expected_width: usize,
+        actual_width: usize,
+    },
+    // a character which is neither a free space nor an antenna frequency
+    InvalidCharacterError {
+        character: char,
+        line_number: usize,
+        column_number: usize,
+    },
+}
+
+impl fmt::Display for AdventOfCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdventOfCodeError::IoError(err) => write!(f, "Cannot read the input: {err}"),
+            AdventOfCodeError::ArgumentError { flag, value, err } => {
+                write!(f, "Invalid value \"{value}\" of flag {flag}: {err}")
+            }
+            AdventOfCodeError::MissingArgumentError { flag } => {
+                write!(f, "Flag {flag} expects a value")
+            }
+            AdventOfCodeError::EmptyMapError => write!(f, "The map is empty"),
+            AdventOfCodeError::RaggedLineError {
+                line_number,
+                expected_width,
+                actual_width,
+            } => write!(
+                f,
+                "Line {line_number} has {actual_width} characters, expected {expected_width}"
+            ),
+            AdventOfCodeError::InvalidCharacterError {
+                character,
+                line_number,
+                column_number,
+            } => write!(
+                f,
+                "Invalid character {character:?} at line {line_number}, column {column_number}"
+            ),
+        }
+    }
 }
 
 impl From<io::Error> for AdventOfCodeError {
@@ -0,0 +1,118 @@
+use crate::antidote_counter::{AntennasMap, Antidotes};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Numbers describing the antennas of one frequency and the antidotes they produce
+#[derive(Debug, PartialEq)]
+pub struct FrequencyStats {
+    pub frequency: char,
+    pub antennas_count: usize,
+    /// Pairs of antennas of this frequency
+    pub pairs_count: usize,
+    /// Unique antidote positions produced by a pair with an antenna of this frequency, an antidote
+    /// produced by several frequencies counts for each of them
+    pub antidotes_count: usize,
+}
+
+/// Counts antennas, their pairs and the antidotes they contributed for every frequency
+///
+/// Returns:
+/// Stats of all frequencies ordered by the frequency character
+pub fn get_frequency_stats(
+    antennas_map: &AntennasMap,
+    antidotes: &Antidotes,
+) -> Vec<FrequencyStats> {
+    let mut antidotes_counts: BTreeMap<char, usize> = BTreeMap::new();
+
+    for pairs in antidotes.values() {
+        let frequencies: BTreeSet<char> = pairs
+            .iter()
+            .flat_map(|(antenna1, antenna2)| [antenna1.character, antenna2.character])
+            .collect();
+
+        for frequency in frequencies {
+            *antidotes_counts.entry(frequency).or_default() += 1;
+        }
+    }
+
+    antennas_map
+        .iter()
+        .map(|(frequency, antennas)| FrequencyStats {
+            frequency: *frequency,
+            antennas_count: antennas.len(),
+            pairs_count: antennas.len() * antennas.len().saturating_sub(1) / 2,
+            antidotes_count: antidotes_counts.get(frequency).copied().unwrap_or_default(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::antidote_counter::{
+        create_antennas_map, find_antidotes, find_antidotes_at_any_grid,
+    };
+    use crate::frequency_stats::{get_frequency_stats, FrequencyStats};
+    use crate::parser::{get_map_dimensions, parse_input};
+
+    const INPUT: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn gets_stats_ordered_by_frequency() {
+        let map = parse_input(INPUT).unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let result = get_frequency_stats(
+            &antennas_map,
+            &find_antidotes(&antennas_map, map_dimensions),
+        );
+
+        // the antidote at (1, 3) is produced by both frequencies
+        assert_eq!(
+            result,
+            vec![
+                FrequencyStats {
+                    frequency: '0',
+                    antennas_count: 4,
+                    pairs_count: 6,
+                    antidotes_count: 10,
+                },
+                FrequencyStats {
+                    frequency: 'A',
+                    antennas_count: 3,
+                    pairs_count: 3,
+                    antidotes_count: 5,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_frequency_without_pairs() {
+        let map = parse_input("b..\n...\n..a").unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let result = get_frequency_stats(
+            &antennas_map,
+            &find_antidotes_at_any_grid(&antennas_map, map_dimensions),
+        );
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|stats| (stats.frequency, stats.pairs_count, stats.antidotes_count))
+                .collect::<Vec<(char, usize, usize)>>(),
+            vec![('a', 0, 0), ('b', 0, 0)]
+        );
+    }
+}
@@ -1,17 +1,91 @@
-use crate::antidote_counter::{calculate_antidotes_at_any_grid_count, calculate_antidotes_count};
+use crate::antidote_counter::{
+    calculate_antidotes_at_any_grid_count, calculate_antidotes_count, find_antidotes,
+    find_antidotes_at_any_grid, find_antidotes_by_rule, AntidoteRule, RuleValueError,
+    TWICE_AS_FAR_RULE,
+};
 use crate::error::AdventOfCodeError;
-use crate::parser::parse_input;
-use std::fs;
+use crate::frequency_stats::get_frequency_stats;
+use crate::parser::{get_map_dimensions, parse_input};
+use crate::render::render_antidotes;
+use std::{env, fs};
 
 mod antenna;
 mod antidote_counter;
 mod error;
+mod frequency_stats;
 mod parser;
+mod render;
+
+// prints the map with antidotes of both parts marked by '#'
+const RENDER_FLAG: &str = "--render";
+// prints the antennas, pairs and antidotes of every frequency for both parts
+const STATS_FLAG: &str = "--stats";
+// followed by "near:far" or "any", counts antidotes by a custom rule with this placement
+const RATIO_FLAG: &str = "--ratio";
+// counts antidotes between the antennas by the custom rule too
+const INNER_FLAG: &str = "--inner";
+// followed by a number or "all", the harmonics beyond each antenna counted by the custom rule
+const HARMONICS_FLAG: &str = "--harmonics";
+// pairs antennas of different frequencies by the custom rule too
+const ACROSS_FREQUENCIES_FLAG: &str = "--across-frequencies";
+const CUSTOM_RULE_FLAGS: [&str; 4] = [
+    RATIO_FLAG,
+    INNER_FLAG,
+    HARMONICS_FLAG,
+    ACROSS_FREQUENCIES_FLAG,
+];
+
+/// Gets the value following the flag, or the default value if the flag is not used
+fn parse_flag_value<T: std::str::FromStr<Err = RuleValueError>>(
+    args: &[String],
+    flag: &str,
+    default: T,
+) -> Result<T, AdventOfCodeError> {
+    let Some(flag_index) = args.iter().position(|arg| arg == flag) else {
+        return Ok(default);
+    };
+    let value =
+        args.get(flag_index + 1)
+            .ok_or_else(|| AdventOfCodeError::MissingArgumentError {
+                flag: flag.to_string(),
+            })?;
+
+    value
+        .parse()
+        .map_err(|err| AdventOfCodeError::ArgumentError {
+            flag: flag.to_string(),
+            value: value.clone(),
+            err,
+        })
+}
+
+/// Builds the custom rule from the flags, unused flags keep the values of the first part's rule
+///
+/// Returns:
+/// A Result containing the rule, or None if no flag of the custom rule is used
+fn parse_custom_rule(args: &[String]) -> Result<Option<AntidoteRule>, AdventOfCodeError> {
+    if !args
+        .iter()
+        .any(|arg| CUSTOM_RULE_FLAGS.contains(&arg.as_str()))
+    {
+        return Ok(None);
+    }
+
+    Ok(Some(AntidoteRule {
+        placement: parse_flag_value(args, RATIO_FLAG, TWICE_AS_FAR_RULE.placement)?,
+        include_inner: args.iter().any(|arg| arg == INNER_FLAG),
+        max_harmonics: parse_flag_value(args, HARMONICS_FLAG, TWICE_AS_FAR_RULE.max_harmonics)?,
+        across_frequencies: args.iter().any(|arg| arg == ACROSS_FREQUENCIES_FLAG),
+    }))
+}
 
 fn main() -> Result<(), AdventOfCodeError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let custom_rule = parse_custom_rule(&args)?;
+
     let input = fs::read_to_string("./data/input.txt")?;
-    let map = parse_input(input.as_str());
-    let map_dimensions = (map.len(), map[0].len());
+    let map = parse_input(input.as_str())?;
+    let map_dimensions = get_map_dimensions(&map);
     let antennas_map = antidote_counter::create_antennas_map(&map);
     let antidotes_count = calculate_antidotes_count(&antennas_map, map_dimensions);
     let antidotes_at_any_grid_count =
@@ -22,5 +96,53 @@ fn main() -> Result<(), AdventOfCodeError> {
         "Number of unique locations with an antinode at any grid: {antidotes_at_any_grid_count}"
     );
 
+    if args.iter().any(|arg| arg == STATS_FLAG) {
+        let stats = get_frequency_stats(
+            &antennas_map,
+            &find_antidotes(&antennas_map, map_dimensions),
+        );
+        let stats_at_any_grid = get_frequency_stats(
+            &antennas_map,
+            &find_antidotes_at_any_grid(&antennas_map, map_dimensions),
+        );
+
+        println!("---------------------------------------");
+        for (frequency_stats, frequency_stats_at_any_grid) in stats.iter().zip(stats_at_any_grid) {
+            println!(
+                "Frequency {}: {} antennas, {} pairs, {} antinodes, {} antinodes at any grid",
+                frequency_stats.frequency,
+                frequency_stats.antennas_count,
+                frequency_stats.pairs_count,
+                frequency_stats.antidotes_count,
+                frequency_stats_at_any_grid.antidotes_count
+            );
+        }
+    }
+
+    if let Some(rule) = custom_rule {
+        let custom_antidotes = find_antidotes_by_rule(&antennas_map, map_dimensions, &rule);
+
+        println!(
+            "Number of unique locations with an antinode by the custom rule: {}",
+            custom_antidotes.len()
+        );
+    }
+
+    if args.iter().any(|arg| arg == RENDER_FLAG) {
+        println!("---------------------------------------");
+        print!(
+            "{}",
+            render_antidotes(&map, &find_antidotes(&antennas_map, map_dimensions))
+        );
+        println!("---------------------------------------");
+        print!(
+            "{}",
+            render_antidotes(
+                &map,
+                &find_antidotes_at_any_grid(&antennas_map, map_dimensions)
+            )
+        );
+    }
+
     Ok(())
 }
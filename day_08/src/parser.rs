@@ -1,5 +1,141 @@
-pub fn parse_input(text: &str) -> Vec<Vec<char>> {
-    let map = text.lines().map(|line| line.chars().collect()).collect();
+use crate::antidote_counter::FREE_SPACE;
+use crate::error::AdventOfCodeError;
 
-    map
+/// Splits the text into rows of characters and checks that it is a rectangular map of free
+/// spaces and antennas. Windows line endings and leading or trailing empty lines are ignored.
+///
+/// Returns:
+/// A Result containing the map, or an error pointing to the first invalid line or character
+pub fn parse_input(text: &str) -> Result<Vec<Vec<char>>, AdventOfCodeError> {
+    let lines: Vec<&str> = text.trim_end_matches(['\r', '\n']).lines().collect();
+    // line numbers of the errors still count the skipped leading empty lines
+    let first_line_index = lines
+        .iter()
+        .position(|line| !line.is_empty())
+        .unwrap_or(lines.len());
+    let map: Vec<Vec<char>> = lines[first_line_index..]
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect();
+    let width = map.first().map_or(0, |row| row.len());
+
+    if width == 0 {
+        return Err(AdventOfCodeError::EmptyMapError);
+    }
+
+    for (row_index, row) in map.iter().enumerate() {
+        if row.len() != width {
+            return Err(AdventOfCodeError::RaggedLineError {
+                line_number: first_line_index + row_index + 1,
+                expected_width: width,
+                actual_width: row.len(),
+            });
+        }
+
+        // antenna frequencies are lowercase letters, uppercase letters and digits
+        if let Some(column_index) = row
+            .iter()
+            .position(|character| *character != FREE_SPACE && !character.is_ascii_alphanumeric())
+        {
+            return Err(AdventOfCodeError::InvalidCharacterError {
+                character: row[column_index],
+                line_number: first_line_index + row_index + 1,
+                column_number: column_index + 1,
+            });
+        }
+    }
+
+    Ok(map)
+}
+
+/// Gets the number of rows and columns of a parsed map
+pub fn get_map_dimensions(map: &[Vec<char>]) -> (usize, usize) {
+    (map.len(), map.first().map_or(0, |row| row.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AdventOfCodeError;
+    use crate::parser::{get_map_dimensions, parse_input};
+
+    #[test]
+    fn parses_map_with_windows_line_endings() {
+        let map = parse_input("..a\r\n0..\r\n...\r\n").unwrap();
+
+        assert_eq!(
+            map,
+            vec![
+                vec!['.', '.', 'a'],
+                vec!['0', '.', '.'],
+                vec!['.', '.', '.']
+            ]
+        );
+        assert_eq!(get_map_dimensions(&map), (3, 3));
+    }
+
+    #[test]
+    fn parses_map_with_leading_empty_lines() {
+        let map = parse_input("\n\r\n..a\n0..\n...").unwrap();
+
+        assert_eq!(
+            map,
+            vec![
+                vec!['.', '.', 'a'],
+                vec!['0', '.', '.'],
+                vec!['.', '.', '.']
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_empty_map() {
+        assert!(matches!(
+            parse_input(""),
+            Err(AdventOfCodeError::EmptyMapError)
+        ));
+        assert!(matches!(
+            parse_input("\n\n"),
+            Err(AdventOfCodeError::EmptyMapError)
+        ));
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let result = parse_input("....\n...\n....");
+
+        assert!(matches!(
+            result,
+            Err(AdventOfCodeError::RaggedLineError {
+                line_number: 2,
+                expected_width: 4,
+                actual_width: 3,
+            })
+        ));
+
+        let result = parse_input("\n....\n...\n....");
+
+        assert!(matches!(
+            result,
+            Err(AdventOfCodeError::RaggedLineError {
+                line_number: 3,
+                expected_width: 4,
+                actual_width: 3,
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_characters_other_than_frequencies() {
+        // the antidote marks of rendered maps are not antennas
+        let result = parse_input("...\n.#.\n...");
+
+        assert!(matches!(
+            result,
+            Err(AdventOfCodeError::InvalidCharacterError {
+                character: '#',
+                line_number: 2,
+                column_number: 2,
+            })
+        ));
+    }
 }
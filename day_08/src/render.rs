@@ -0,0 +1,106 @@
+use crate::antidote_counter::{Antidotes, FREE_SPACE};
+
+const ANTIDOTE_SYMBOL: char = '#';
+
+/// Renders the map with antidotes marked by '#', antennas are kept even if an antidote is at
+/// the same position, as in the puzzle text
+///
+/// Returns:
+/// A multiline string with the rendered map
+pub fn render_antidotes(map: &[Vec<char>], antidotes: &Antidotes) -> String {
+    let mut rendered = String::new();
+
+    for (row_index, row) in map.iter().enumerate() {
+        for (column_index, character) in row.iter().enumerate() {
+            let position = (row_index as isize, column_index as isize);
+
+            if *character == FREE_SPACE && antidotes.contains_key(&position) {
+                rendered.push(ANTIDOTE_SYMBOL);
+            } else {
+                rendered.push(*character);
+            }
+        }
+
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::antidote_counter::{
+        create_antennas_map, find_antidotes, find_antidotes_at_any_grid,
+    };
+    use crate::parser::{get_map_dimensions, parse_input};
+    use crate::render::render_antidotes;
+
+    const INPUT: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn renders_antidotes() {
+        let map = parse_input(INPUT).unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let result = render_antidotes(&map, &find_antidotes(&antennas_map, map_dimensions));
+
+        assert_eq!(
+            result,
+            "\
+......#....#
+...#....0...
+....#0....#.
+..#....0....
+....0....#..
+.#....A.....
+...#........
+#......#....
+........A...
+.........A..
+..........#.
+..........#.
+"
+        );
+    }
+
+    #[test]
+    fn renders_antidotes_at_any_grid() {
+        let map = parse_input(INPUT).unwrap();
+        let map_dimensions = get_map_dimensions(&map);
+        let antennas_map = create_antennas_map(&map);
+        let result = render_antidotes(
+            &map,
+            &find_antidotes_at_any_grid(&antennas_map, map_dimensions),
+        );
+
+        assert_eq!(
+            result,
+            "\
+##....#....#
+.#.#....0...
+..#.#0....#.
+..##...0....
+....0....#..
+.#...#A....#
+...#..#.....
+#....#.#....
+..#.....A...
+....#....A..
+.#........#.
+...#......##
+"
+        );
+    }
+}
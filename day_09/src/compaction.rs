@@ -0,0 +1,170 @@
+use crate::disk_map::{Block, DiskMap, Span};
+use crate::free_space_index::FreeSpaceIndex;
+
+/// Moves file blocks one at a time from the end of the disk to the leftmost free block until
+/// there are no gaps between the file blocks
+///
+/// Returns:
+/// The blocks of the compacted disk
+pub fn compact_blocks(disk_map: &DiskMap) -> Vec<Block> {
+    let mut blocks = disk_map.blocks();
+
+    if blocks.is_empty() {
+        return blocks;
+    }
+
+    let (mut free_index, mut file_index) = (0, blocks.len() - 1);
+
+    loop {
+        while free_index < blocks.len() && blocks[free_index].is_some() {
+            free_index += 1;
+        }
+        while file_index > 0 && blocks[file_index].is_none() {
+            file_index -= 1;
+        }
+        if free_index >= file_index {
+            break;
+        }
+
+        blocks.swap(free_index, file_index);
+    }
+
+    blocks
+}
+
+/// Moves whole files, in order of decreasing file id, to the leftmost span of free space which
+/// is big enough to hold them. Every file is moved at most once and only to the left.
+///
+/// Returns:
+/// The spans of the files after compaction, the index of a span is the id of its file
+pub fn compact_files(disk_map: &DiskMap) -> Vec<Span> {
+    let mut files = disk_map.files.clone();
+    let mut free_space_index = FreeSpaceIndex::new(&disk_map.free_spans);
+
+    // the space left by a file is never used, because files with lower ids lie to the left of it
+    // and never move to the right, empty files have no blocks to move at all
+    for file in files.iter_mut().rev().filter(|file| file.length > 0) {
+        if let Some(start) = free_space_index.take_leftmost(file.length, file.start) {
+            file.start = start;
+        }
+    }
+
+    files
+}
+
+/// Computes the filesystem checksum, the sum of block positions multiplied by the file ids
+/// stored in them
+pub fn calculate_checksum(blocks: &[Block]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(position, block)| block.map(|file_id| position * file_id))
+        .sum()
+}
+
+/// Computes the filesystem checksum of file spans without expanding them to blocks
+pub fn calculate_files_checksum(files: &[Span]) -> usize {
+    files
+        .iter()
+        .enumerate()
+        .map(|(file_id, span)| file_id * (span.start..span.start + span.length).sum::<usize>())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compaction::{
+        calculate_checksum, calculate_files_checksum, compact_blocks, compact_files,
+    };
+    use crate::disk_map::{DiskMap, Span};
+    use crate::parse::parse_input;
+
+    const INPUT: &str = "2333133121414131402";
+
+    /// Moves whole files by scanning the free spans from the left for every file
+    fn compact_files_by_scanning(disk_map: &DiskMap) -> Vec<Span> {
+        let mut files = disk_map.files.clone();
+        let mut free_spans = disk_map.free_spans.clone();
+
+        for file in files.iter_mut().rev().filter(|file| file.length > 0) {
+            let fitting_span = free_spans
+                .iter_mut()
+                .take_while(|free_span| free_span.start < file.start)
+                .find(|free_span| free_span.length >= file.length);
+
+            if let Some(free_span) = fitting_span {
+                file.start = free_span.start;
+                free_span.start += file.length;
+                free_span.length -= file.length;
+            }
+        }
+
+        files
+    }
+
+    #[test]
+    fn compacts_example_block_by_block() {
+        let disk_map = parse_input(INPUT).unwrap();
+        let blocks = compact_blocks(&disk_map);
+
+        assert_eq!(
+            blocks.iter().flatten().copied().collect::<Vec<usize>>(),
+            vec![
+                0, 0, 9, 9, 8, 1, 1, 1, 8, 8, 8, 2, 7, 7, 7, 3, 3, 3, 6, 4, 4, 6, 5, 5, 5, 5, 6, 6
+            ]
+        );
+        assert!(blocks[28..].iter().all(|block| block.is_none()));
+        assert_eq!(calculate_checksum(&blocks), 1928);
+    }
+
+    #[test]
+    fn compacts_example_file_by_file() {
+        let disk_map = parse_input(INPUT).unwrap();
+        let files = compact_files(&disk_map);
+
+        assert_eq!(
+            files[9],
+            Span {
+                start: 2,
+                length: 2
+            }
+        );
+        assert_eq!(
+            files[2],
+            Span {
+                start: 4,
+                length: 1
+            }
+        );
+        // file 8 does not fit anywhere to its left
+        assert_eq!(files[8], disk_map.files[8]);
+        assert_eq!(calculate_files_checksum(&files), 2858);
+    }
+
+    #[test]
+    fn keeps_files_which_do_not_fit_and_empty_disk() {
+        let disk_map = parse_input("213").unwrap();
+
+        assert_eq!(calculate_checksum(&compact_blocks(&disk_map)), 9);
+        // the free block is too small for the whole file 1
+        assert_eq!(calculate_files_checksum(&compact_files(&disk_map)), 12);
+        assert!(compact_blocks(&parse_input("").unwrap()).is_empty());
+    }
+
+    #[test]
+    fn compacts_files_like_scanning_from_the_left() {
+        let mut rng = fastrand::Rng::with_seed(2024);
+
+        for _ in 0..1000 {
+            let length = rng.usize(1..60);
+            let input: String = (0..length).map(|_| rng.digit(10)).collect();
+            let disk_map = parse_input(&input).unwrap();
+
+            assert_eq!(
+                compact_files(&disk_map),
+                compact_files_by_scanning(&disk_map),
+                "{input}"
+            );
+        }
+    }
+}
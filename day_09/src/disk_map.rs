@@ -0,0 +1,54 @@
+/// A block of the disk holds a part of the file with the given id, or nothing if it is free
+pub type Block = Option<usize>;
+
+/// A run of consecutive blocks on the disk
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// The layout of files and free space described by the disk map
+#[derive(Debug, PartialEq)]
+pub struct DiskMap {
+    /// Spans of files, the index of a span is the id of its file
+    pub files: Vec<Span>,
+    /// Spans of free space between the files from left to right, empty spans are left out
+    pub free_spans: Vec<Span>,
+}
+
+impl DiskMap {
+    /// Gets the number of blocks of the disk including the free space
+    pub fn size(&self) -> usize {
+        let files_end = self.files.last().map_or(0, |span| span.start + span.length);
+        let free_spans_end = self
+            .free_spans
+            .last()
+            .map_or(0, |span| span.start + span.length);
+
+        files_end.max(free_spans_end)
+    }
+
+    /// Expands the spans to single blocks
+    ///
+    /// Returns:
+    /// A vector with one item for every block of the disk
+    pub fn blocks(&self) -> Vec<Block> {
+        files_to_blocks(&self.files, self.size())
+    }
+}
+
+/// Expands spans of files to single blocks of a disk with the given size, the index of a span is
+/// the id of its file
+///
+/// Returns:
+/// A vector with one item for every block of the disk
+pub fn files_to_blocks(files: &[Span], disk_size: usize) -> Vec<Block> {
+    let mut blocks = vec![None; disk_size];
+
+    for (file_id, span) in files.iter().enumerate() {
+        blocks[span.start..span.start + span.length].fill(Some(file_id));
+    }
+
+    blocks
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum AdventOfCodeError {
+    IoError(io::Error),
+    // a character of the disk map which is not a digit, the position is counted from zero
+    InvalidDigitError { position: usize, character: char },
+}
+
+impl fmt::Display for AdventOfCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdventOfCodeError::IoError(err) => write!(f, "Cannot read the input: {err}"),
+            AdventOfCodeError::InvalidDigitError {
+                position,
+                character,
+            } => write!(
+                f,
+                "The disk map contains {character:?} at position {position}, expected a digit"
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for AdventOfCodeError {
+    fn from(err: io::Error) -> Self {
+        AdventOfCodeError::IoError(err)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::disk_map::Span;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Free spans are at most nine blocks long, because every length in the disk map is one digit
+pub const MAX_SPAN_LENGTH: usize = 9;
+
+/// Keeps the start positions of free spans in one min-heap per span length, so the leftmost span
+/// which can hold a file is found by looking at the top of at most nine heaps
+#[derive(Debug, Default)]
+pub struct FreeSpaceIndex {
+    // the heap at index `length` holds starts of free spans with exactly this length
+    heaps: [BinaryHeap<Reverse<usize>>; MAX_SPAN_LENGTH + 1],
+}
+
+impl FreeSpaceIndex {
+    pub fn new(free_spans: &[Span]) -> Self {
+        let mut index = FreeSpaceIndex::default();
+
+        for span in free_spans {
+            index.insert(*span);
+        }
+
+        index
+    }
+
+    /// Adds a free span, empty spans are ignored
+    fn insert(&mut self, span: Span) {
+        if span.length > 0 {
+            self.heaps[span.length].push(Reverse(span.start));
+        }
+    }
+
+    /// Takes the leftmost free span which is at least `length` blocks long and starts before the
+    /// given position. The blocks left over after the first `length` blocks stay free.
+    ///
+    /// Returns:
+    /// The start of the taken blocks, or None if no free span fits
+    pub fn take_leftmost(&mut self, length: usize, before: usize) -> Option<usize> {
+        let span_length = (length..=MAX_SPAN_LENGTH)
+            .filter_map(|span_length| {
+                self.heaps[span_length]
+                    .peek()
+                    .map(|Reverse(start)| (*start, span_length))
+            })
+            .filter(|(start, _)| *start < before)
+            .min()
+            .map(|(_, span_length)| span_length)?;
+        let Reverse(start) = self.heaps[span_length].pop()?;
+
+        self.insert(Span {
+            start: start + length,
+            length: span_length - length,
+        });
+
+        Some(start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::disk_map::Span;
+    use crate::free_space_index::FreeSpaceIndex;
+
+    #[test]
+    fn takes_leftmost_fitting_span() {
+        let mut index = FreeSpaceIndex::new(&[
+            Span {
+                start: 2,
+                length: 1,
+            },
+            Span {
+                start: 5,
+                length: 3,
+            },
+            Span {
+                start: 10,
+                length: 4,
+            },
+        ]);
+
+        assert_eq!(index.take_leftmost(2, 20), Some(5));
+        // the rest of the span at 5 is the leftmost span with a single block after (2, 1)
+        assert_eq!(index.take_leftmost(1, 20), Some(2));
+        assert_eq!(index.take_leftmost(1, 20), Some(7));
+        assert_eq!(index.take_leftmost(4, 10), None);
+        assert_eq!(index.take_leftmost(4, 11), Some(10));
+        assert_eq!(index.take_leftmost(1, 20), None);
+    }
+}
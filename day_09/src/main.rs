@@ -1,3 +1,60 @@
-fn main() {
-    println!("Welcome to Day 9!");
+use crate::compaction::{
+    calculate_checksum, calculate_files_checksum, compact_blocks, compact_files,
+};
+use crate::disk_map::files_to_blocks;
+use crate::error::AdventOfCodeError;
+use crate::parse::parse_input;
+use crate::render::render_blocks;
+use std::{env, fs};
+
+mod compaction;
+mod disk_map;
+mod error;
+mod free_space_index;
+mod parse;
+mod render;
+
+// used when no other input path is given as the first argument, ./data/example.txt is the
+// example of the puzzle
+const INPUT_PATH: &str = "./data/input.txt";
+// prints the disk layout before and after both kinds of compaction
+const RENDER_FLAG: &str = "--render";
+// bigger disks are not rendered, their layout would not fit on the screen
+const MAX_RENDERED_BLOCKS: usize = 200;
+
+fn main() -> Result<(), AdventOfCodeError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let input_path = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .map_or(INPUT_PATH, |arg| arg.as_str());
+
+    let input = fs::read_to_string(input_path)?;
+    let disk_map = parse_input(&input)?;
+    let compacted_blocks = compact_blocks(&disk_map);
+    let compacted_files = compact_files(&disk_map);
+    let blocks_checksum = calculate_checksum(&compacted_blocks);
+    let files_checksum = calculate_files_checksum(&compacted_files);
+
+    println!("Filesystem checksum after moving blocks: {blocks_checksum}");
+    println!("Filesystem checksum after moving whole files: {files_checksum}");
+
+    if args.iter().any(|arg| arg == RENDER_FLAG) {
+        println!("---------------------------------------");
+        if disk_map.size() > MAX_RENDERED_BLOCKS {
+            println!(
+                "The disk has {} blocks, only disks with up to {MAX_RENDERED_BLOCKS} blocks are rendered",
+                disk_map.size()
+            );
+        } else {
+            println!("{}", render_blocks(&disk_map.blocks()));
+            println!("{}", render_blocks(&compacted_blocks));
+            println!(
+                "{}",
+                render_blocks(&files_to_blocks(&compacted_files, disk_map.size()))
+            );
+        }
+    }
+
+    Ok(())
 }
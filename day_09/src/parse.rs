@@ -0,0 +1,101 @@
+use crate::disk_map::{DiskMap, Span};
+use crate::error::AdventOfCodeError;
+
+/// Parses the dense disk map where digits alternate between the length of a file and the length
+/// of the free space after it, files get ids in the order they appear
+///
+/// Returns:
+/// A Result containing the disk map, or an error with the position of the first invalid character
+pub fn parse_input(text: &str) -> Result<DiskMap, AdventOfCodeError> {
+    let mut disk_map = DiskMap {
+        files: vec![],
+        free_spans: vec![],
+    };
+    let mut start = 0;
+
+    for (position, character) in text.trim_end().chars().enumerate() {
+        let length = character
+            .to_digit(10)
+            .ok_or(AdventOfCodeError::InvalidDigitError {
+                position,
+                character,
+            })? as usize;
+        let span = Span { start, length };
+
+        if position % 2 == 0 {
+            disk_map.files.push(span);
+        } else if length > 0 {
+            disk_map.free_spans.push(span);
+        }
+        start += length;
+    }
+
+    Ok(disk_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::disk_map::Span;
+    use crate::error::AdventOfCodeError;
+    use crate::parse::parse_input;
+
+    #[test]
+    fn parses_files_and_free_spans() {
+        let disk_map = parse_input("12345\n").unwrap();
+
+        assert_eq!(
+            disk_map.files,
+            vec![
+                Span {
+                    start: 0,
+                    length: 1
+                },
+                Span {
+                    start: 3,
+                    length: 3
+                },
+                Span {
+                    start: 10,
+                    length: 5
+                },
+            ]
+        );
+        assert_eq!(
+            disk_map.free_spans,
+            vec![
+                Span {
+                    start: 1,
+                    length: 2
+                },
+                Span {
+                    start: 6,
+                    length: 4
+                },
+            ]
+        );
+        assert_eq!(disk_map.size(), 15);
+    }
+
+    #[test]
+    fn expands_disk_map_to_blocks() {
+        let disk_map = parse_input("12101").unwrap();
+
+        assert_eq!(
+            disk_map.blocks(),
+            vec![Some(0), None, None, Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_digit() {
+        let result = parse_input("12x4");
+
+        assert!(matches!(
+            result,
+            Err(AdventOfCodeError::InvalidDigitError {
+                position: 2,
+                character: 'x',
+            })
+        ));
+    }
+}
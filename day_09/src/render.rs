@@ -0,0 +1,56 @@
+use crate::disk_map::Block;
+
+const FREE_BLOCK_SYMBOL: char = '.';
+// file ids which cannot be written as a single base 36 digit
+const LARGE_FILE_ID_SYMBOL: char = '#';
+
+/// Renders blocks of the disk like the puzzle text, e.g. "00...111...2...". Files are written
+/// by their id in base 36, so only the ids of the first 36 files can be told apart.
+///
+/// Returns:
+/// A single line string with one character for every block
+pub fn render_blocks(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(|block| match block {
+            Some(file_id) => u32::try_from(*file_id)
+                .ok()
+                .and_then(|file_id| char::from_digit(file_id, 36))
+                .unwrap_or(LARGE_FILE_ID_SYMBOL),
+            None => FREE_BLOCK_SYMBOL,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compaction::{compact_blocks, compact_files};
+    use crate::disk_map::files_to_blocks;
+    use crate::parse::parse_input;
+    use crate::render::render_blocks;
+
+    const INPUT: &str = "2333133121414131402";
+
+    #[test]
+    fn renders_example_before_and_after_compaction() {
+        let disk_map = parse_input(INPUT).unwrap();
+
+        assert_eq!(
+            render_blocks(&disk_map.blocks()),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+        assert_eq!(
+            render_blocks(&compact_blocks(&disk_map)),
+            "0099811188827773336446555566.............."
+        );
+        assert_eq!(
+            render_blocks(&files_to_blocks(&compact_files(&disk_map), disk_map.size())),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+    }
+
+    #[test]
+    fn renders_large_file_ids() {
+        assert_eq!(render_blocks(&[Some(10), Some(35), None, Some(36)]), "az.#");
+    }
+}